@@ -1,127 +1,335 @@
-// bench.rs
-#![feature(test)]
-extern crate test;
-
-use smolprng::{
-    JsfLarge, MiddleSquare, Sfc32, Sfc32Small, SplitMix32, SplitMix64, StepGenerator128,
-    StepGenerator16, StepGenerator32, StepGenerator64, StepGenerator8, XoShiro256Plus,
-    XoShiro256PlusPlus, XoShiro256SuperStar, XorShift128, XorShift128Plus, XorShift32, XorShift64,
-    XoroShiro128Plus, XoroShiro128PlusPlus, XoroShiro128SuperStar, LCG, PRNG,
-};
-use test::Bencher;
-
-use std::time::SystemTime;
-
-///Default test it to generate 1kB of random bits using 64 bit generator
-
-macro_rules! bench_maker_1024_byte_by_64_byte {
-    ($fn_name:ident, $generator_type:ident) => {
-        #[bench]
-        fn $fn_name(b: &mut Bencher) {
-            let mut buffer = [0u64; SIZE];
-            let a = SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .unwrap()
-                .as_secs();
-            let mut prng = PRNG {
-                generator: $generator_type::from(a),
-            };
-            const SIZE: usize = 128;
-            b.iter(|| {
-                for i in 0..SIZE {
-                    buffer[i] = prng.gen_u64();
-                }
-            })
+//! Benchmarks every algorithm on stable Rust, run with ``cargo bench --features std``
+//!
+//! Reports the GB/s of raw output, the ns per call of every ``gen_*`` width and float, and the ns per sample of every
+//! distribution that the enabled features provide, including the multivariate, geometric, rotation and weighted
+//! samplers. Each is the median of several runs with the fastest and slowest run as the spread. The results are printed and written as JSON to ``bench_generators.json`` in the target directory, which
+//! follows ``CARGO_TARGET_DIR``, or to the path given with ``--json PATH``. Any other arguments filter the
+//! algorithms by name, so ``cargo bench -- jsf64 sfc32`` only runs those two.
+
+use smolprng::*;
+use std::fmt::Write as _;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+/// Runs are calibrated to take at least this long
+const RUN_TIME: Duration = Duration::from_millis(10);
+
+/// The median of this many calibrated runs is reported
+const RUNS: usize = 11;
+
+/// The size of the buffer filled for the raw output benchmark
+const BUFFER_SIZE: usize = 1024;
+
+/// The nanoseconds per call of the median, fastest and slowest run
+struct Timing {
+    median: f64,
+    min: f64,
+    max: f64,
+}
+
+struct Measurement {
+    algorithm: &'static str,
+    benchmark: &'static str,
+    timing: Timing,
+    gb_per_s: Option<f64>,
+}
+
+impl Measurement {
+    fn print(&self) {
+        print!(
+            "{:<16} {:<24} {:>10.2} ns/op [{:.2} .. {:.2}]",
+            self.algorithm, self.benchmark, self.timing.median, self.timing.min, self.timing.max
+        );
+        match self.gb_per_s {
+            Some(gb_per_s) => println!(" {gb_per_s:>8.2} GB/s"),
+            None => println!(),
         }
-    };
+    }
 }
 
-macro_rules! bench_maker_1_000_000_fp {
-    ($fn_name_1:ident,$fn_name_2:ident, $generator_type:ident) => {
-        #[bench]
-        fn $fn_name_1(b: &mut Bencher) {
-            let mut prng = PRNG {
-                generator: $generator_type::default(),
-            };
-            const SIZE: usize = 1_000_000;
-            let mut buffer = 0f32;
-
-            b.iter(|| {
-                for _i in 0..SIZE {
-                    buffer += prng.gen_f32();
-                }
-                buffer = 0f32;
-            })
+/// Times `op` over several runs in nanoseconds per call
+#[allow(clippy::cast_precision_loss)]
+fn time<F: FnMut()>(mut op: F) -> Timing {
+    let mut run = |iterations: u64| {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            op();
         }
+        start.elapsed()
+    };
+
+    let mut iterations = 1;
+    while run(iterations) < RUN_TIME {
+        iterations *= 2;
+    }
+
+    let mut runs = [0f64; RUNS];
+    for ns in &mut runs {
+        *ns = run(iterations).as_nanos() as f64 / iterations as f64;
+    }
+    runs.sort_by(f64::total_cmp);
+
+    Timing {
+        median: runs[RUNS / 2],
+        min: runs[0],
+        max: runs[RUNS - 1],
+    }
+}
 
-        #[bench]
-        fn $fn_name_2(b: &mut Bencher) {
-            let mut prng = PRNG {
-                generator: $generator_type::default(),
+macro_rules! measure {
+    ($results:ident, $algorithm:ident, [$($benchmark:literal => $op:expr),+ $(,)?]) => {
+        $(
+            let measurement = Measurement {
+                algorithm: $algorithm,
+                benchmark: $benchmark,
+                timing: time(|| {
+                    black_box($op);
+                }),
+                gb_per_s: None,
             };
-            const SIZE: usize = 1_000_000;
-            let mut buffer = 0f64;
-
-            b.iter(|| {
-                for _i in 0..SIZE {
-                    buffer += prng.gen_f64();
-                }
-                buffer = 0f64;
-            })
-        }
+            measurement.print();
+            $results.push(measurement);
+        )+
     };
 }
 
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_jsf_large, JsfLarge}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_lcg, LCG}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_middle_square, MiddleSquare}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_sfc32, Sfc32}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_sfc_small, Sfc32Small}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_splitmix_32, SplitMix32}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_splitmix_64, SplitMix64}
-
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_stepgenerator_8, StepGenerator8}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_stepgenerator_16, StepGenerator16}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_stepgenerator_32, StepGenerator32}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_stepgenerator_64, StepGenerator64}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_stepgenerator_128, StepGenerator128}
-
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_xoroshiro_super_star, XoroShiro128SuperStar}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_xoroshiro_plus_plus, XoroShiro128PlusPlus}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_xoroshiro_plus, XoroShiro128Plus}
-
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_xorshift_32, XorShift32}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_xorshift_64, XorShift64}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_xorshift_128, XorShift128}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_xorshift_128_plus, XorShift128Plus}
-
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_xoshiro_256_plus_plus, XoShiro256PlusPlus}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_xoshiro_256_plus, XoShiro256Plus}
-bench_maker_1024_byte_by_64_byte! {bench_1024_bytes_xoshiro_256_super_star, XoShiro256SuperStar}
-
-bench_maker_1_000_000_fp! {bench_1m_fp64_jsf_large,bench_1m_fp32_jsf_large, JsfLarge}
-bench_maker_1_000_000_fp! {bench_1m_fp64_lcg,bench_1m_fp32_lcg, LCG}
-bench_maker_1_000_000_fp! {bench_1m_fp64_middle_square,bench_1m_fp32_middle_square, MiddleSquare}
-bench_maker_1_000_000_fp! {bench_1m_fp64_sfc32,bench_1m_fp32_sfc32, Sfc32}
-bench_maker_1_000_000_fp! {bench_1m_fp64_sfc_small,bench_1m_fp32_sfc_small, Sfc32Small}
-bench_maker_1_000_000_fp! {bench_1m_fp64_splitmix_32,bench_1m_fp32_splitmix_32, SplitMix32}
-bench_maker_1_000_000_fp! {bench_1m_fp64_splitmix_64,bench_1m_fp32_splitmix_64, SplitMix64}
-
-bench_maker_1_000_000_fp! {bench_1m_fp64_stepgenerator_8,bench_1m_fp32_stepgenerator_8, StepGenerator8}
-bench_maker_1_000_000_fp! {bench_1m_fp64_stepgenerator_16,bench_1m_fp32_stepgenerator_16, StepGenerator16}
-bench_maker_1_000_000_fp! {bench_1m_fp64_stepgenerator_32,bench_1m_fp32_stepgenerator_32, StepGenerator32}
-bench_maker_1_000_000_fp! {bench_1m_fp64_stepgenerator_64,bench_1m_fp32_stepgenerator_64, StepGenerator64}
-bench_maker_1_000_000_fp! {bench_1m_fp64_stepgenerator_128,bench_1m_fp32_stepgenerator_128, StepGenerator128}
-
-bench_maker_1_000_000_fp! {bench_1m_fp64_xoroshiro_super_star,bench_1m_fp32_xoroshiro_super_star, XoroShiro128SuperStar}
-bench_maker_1_000_000_fp! {bench_1m_fp64_xoroshiro_plus_plus,bench_1m_fp32_xoroshiro_plus_plus, XoroShiro128PlusPlus}
-bench_maker_1_000_000_fp! {bench_1m_fp64_xoroshiro_plus,bench_1m_fp32_xoroshiro_plus, XoroShiro128Plus}
-
-bench_maker_1_000_000_fp! {bench_1m_fp64_xorshift_32,bench_1m_fp32_xorshift_32, XorShift32}
-bench_maker_1_000_000_fp! {bench_1m_fp64_xorshift_64,bench_1m_fp32_xorshift_64, XorShift64}
-bench_maker_1_000_000_fp! {bench_1m_fp64_xorshift_128,bench_1m_fp32_xorshift_128, XorShift128}
-bench_maker_1_000_000_fp! {bench_1m_fp64_xorshift_128_plus,bench_1m_fp32_xorshift_128_plus, XorShift128Plus}
-
-bench_maker_1_000_000_fp! {bench_1m_fp64_xoshiro_256_plus_plus,bench_1m_fp32_xoshiro_256_plus_plus, XoShiro256PlusPlus}
-bench_maker_1_000_000_fp! {bench_1m_fp64_xoshiro_256_plus,bench_1m_fp32_xoshiro_256_plus, XoShiro256Plus}
-bench_maker_1_000_000_fp! {bench_1m_fp64_xoshiro_256_super_star,bench_1m_fp32_xoshiro_256_super_star, XoShiro256SuperStar}
+fn bench<T: Algorithm + Default>(
+    type_name: &'static str,
+    filters: &[String],
+    results: &mut Vec<Measurement>,
+) {
+    let algorithm = registry::find(type_name).map_or(type_name, |info| info.name);
+    if !filters.is_empty()
+        && !filters
+            .iter()
+            .any(|filter| algorithm.contains(filter.as_str()))
+    {
+        return;
+    }
+
+    let mut prng = PRNG {
+        generator: T::default(),
+    };
+
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let timing = time(|| {
+        prng.generator.fill_bytes(&mut buffer);
+        black_box(&buffer);
+    });
+    #[allow(clippy::cast_precision_loss)]
+    let measurement = Measurement {
+        algorithm,
+        benchmark: "fill_bytes_1024",
+        gb_per_s: Some(BUFFER_SIZE as f64 / timing.median),
+        timing,
+    };
+    measurement.print();
+    results.push(measurement);
+
+    let weights = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0];
+    let weighted_index = DynamicWeightedIndex::new(&weights);
+    let mvn = MultivariateNormal::new(
+        &[0.0; 3],
+        &[1.0, 0.5, 0.25, 0.5, 1.0, 0.5, 0.25, 0.5, 1.0],
+        3,
+    )
+    .expect("the covariance is positive-definite");
+    let triangle = Triangle::new([0.0, 0.0], [1.0, 0.0], [0.0, 1.0]);
+    let polygon = Polygon::new(&[
+        [0.0, 0.0],
+        [2.0, 0.0],
+        [2.0, 1.0],
+        [1.0, 1.0],
+        [1.0, 2.0],
+        [0.0, 2.0],
+    ])
+    .expect("the L shape is a simple polygon");
+    let mut vector = [0f64; 3];
+    let mut counts = [0u64; 3];
+
+    measure!(results, algorithm, [
+        "gen_bool" => prng.gen_bool(),
+        "gen_u8" => prng.gen_u8(),
+        "gen_u16" => prng.gen_u16(),
+        "gen_u32" => prng.gen_u32(),
+        "gen_u64" => prng.gen_u64(),
+        "gen_u128" => prng.gen_u128(),
+        "gen_f32" => prng.gen_f32(),
+        "gen_f64" => prng.gen_f64(),
+        "bernoulli" => prng.bernoulli(0.3),
+        "binomial" => prng.binomial(20, 0.3),
+        "poisson" => prng.poisson(4.0),
+        "gen_u64_in_range" => prng.gen_u64_in_range(1000),
+        "multinomial" => {
+            Multinomial::sample(&mut prng, 100, &[0.2, 0.3, 0.5], &mut counts);
+            counts
+        },
+        "multivariate_normal" => {
+            mvn.sample(&mut prng, &mut vector);
+            vector
+        },
+        "triangle" => triangle.sample(&mut prng),
+        "polygon" => polygon.sample(&mut prng),
+        "weighted_index" => weighted_index.sample(&mut prng),
+    ]);
+
+    #[cfg(feature = "std")]
+    let (dirichlet, annulus, mut orthogonal) = (
+        Dirichlet::new(&[0.5, 1.0, 2.0]).expect("the alphas are positive"),
+        Annulus::new(0.5, 1.0),
+        [0f64; 64],
+    );
+
+    #[cfg(feature = "std")]
+    measure!(results, algorithm, [
+        "normal" => prng.normal(),
+        "lognormal" => prng.lognormal(),
+        "cauchy" => prng.cauchy(),
+        "student_t" => prng.student_t(5.0),
+        "gamma" => prng.gamma(2.0, 1.0),
+        "chi_squared" => prng.chi_squared(3.0),
+        "beta" => prng.beta(2.0, 3.0),
+        "exponential" => prng.exponential(1.0),
+        "logistic" => prng.logistic(0.0, 1.0),
+        "fischer" => prng.fischer(5.0, 10.0),
+        "negative_binomial" => prng.negative_binomial(3.0, 0.5),
+        "dirichlet" => {
+            dirichlet.sample(&mut prng, &mut vector);
+            vector
+        },
+        "unit_circle" => UnitCircle.sample(&mut prng),
+        "unit_sphere" => UnitSphere.sample_array::<_, 3>(&mut prng),
+        "unit_ball" => UnitBall.sample_array::<_, 3>(&mut prng),
+        "unit_simplex" => UnitSimplex.sample_array::<_, 3>(&mut prng),
+        "annulus" => annulus.sample(&mut prng),
+        "quaternion" => prng.random_quaternion(),
+        "rotation_3d" => prng.random_rotation_3d(),
+        "orthogonal_8" => {
+            prng.random_orthogonal(8, &mut orthogonal);
+            orthogonal
+        },
+        "choose_weighted_multiple" => prng.choose_weighted_multiple(&weights, 3),
+    ]);
+}
+
+macro_rules! bench_all {
+    ($filters:ident, $results:ident, [$($algo_type:ident),+ $(,)?]) => {
+        $(bench::<$algo_type>(stringify!($algo_type), &$filters, &mut $results);)+
+    };
+}
+
+/// The JSON results go in ``CARGO_TARGET_DIR`` when it is set, a relative path being relative to the package
+fn default_json_path() -> PathBuf {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = std::env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| manifest_dir.join("target"), |dir| manifest_dir.join(dir));
+    target_dir.join("bench_generators.json")
+}
+
+fn main() {
+    let mut json_path = default_json_path();
+    let mut filters = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // passed by cargo bench
+            "--bench" => {}
+            "--json" => json_path = args.next().expect("--json needs a path").into(),
+            _ => filters.push(arg),
+        }
+    }
+
+    let mut results = Vec::new();
+    bench_all!(
+        filters,
+        results,
+        [
+            ChaCha8,
+            ChaCha12,
+            ChaCha20,
+            Cmwc4096,
+            JsfLarge,
+            JsfSmall,
+            Kiss99,
+            LCG,
+            Lehmer64,
+            Lfsr113,
+            MiddleSquare,
+            Mrg32k3a,
+            Mt19937,
+            Mt19937_64,
+            Mwc128,
+            Mwc192,
+            Mwc256,
+            Philox4x32_10,
+            RomuDuoJr,
+            RomuQuad,
+            RomuTrio,
+            Sfc32,
+            Sfc32Small,
+            Sfc64,
+            SplitMix32,
+            SplitMix64,
+            Squares32,
+            Squares64,
+            StepGenerator8,
+            StepGenerator16,
+            StepGenerator32,
+            StepGenerator64,
+            StepGenerator128,
+            Taus88,
+            Threefry4x64_20,
+            WyRand,
+            XoroShiro1024PlusPlus,
+            XoroShiro1024Star,
+            XoroShiro1024SuperStar,
+            XoroShiro128Plus,
+            XoroShiro128PlusPlus,
+            XoroShiro128SuperStar,
+            XorShift32,
+            XorShift64,
+            XorShift128,
+            XorShift128Plus,
+            Xorwow,
+            XoShiro128Plus,
+            XoShiro128PlusPlus,
+            XoShiro128SuperStar,
+            XoShiro256Plus,
+            XoShiro256PlusPlus,
+            XoShiro256SuperStar,
+            XoShiro512PlusPlus,
+            XoShiro512SuperStar,
+        ]
+    );
+
+    std::fs::write(&json_path, to_json(&results)).expect("could not write the JSON results");
+    println!("\nwrote {}", json_path.display());
+}
+
+fn to_json(results: &[Measurement]) -> String {
+    let mut json = format!(
+        "{{\n  \"crate_version\": \"{}\",\n  \"results\": [\n",
+        env!("CARGO_PKG_VERSION")
+    );
+
+    for (i, result) in results.iter().enumerate() {
+        let _ = write!(
+            json,
+            "    {{\"algorithm\": \"{}\", \"benchmark\": \"{}\", \"ns_per_op\": {:.4}, \"ns_per_op_min\": {:.4}, \"ns_per_op_max\": {:.4}",
+            result.algorithm,
+            result.benchmark,
+            result.timing.median,
+            result.timing.min,
+            result.timing.max
+        );
+        if let Some(gb_per_s) = result.gb_per_s {
+            let _ = write!(json, ", \"gb_per_s\": {gb_per_s:.4}");
+        }
+        json.push_str(if i + 1 < results.len() { "},\n" } else { "}\n" });
+    }
+
+    json.push_str("  ]\n}\n");
+    json
+}
@@ -1,21 +1,43 @@
 //! This is the module that contains all the algorithms implmented in the package
 
+pub mod chacha;
 pub mod jsf;
+pub mod kiss;
 pub mod lcg;
+pub mod lehmer;
+pub mod mersenne_twister;
 pub mod middle_square;
+pub mod mrg;
+pub mod mwc;
+pub mod random123;
+pub mod romu;
 pub mod scf32;
 pub mod splitmix;
+pub mod squares;
 pub mod step_generators;
+pub mod tausworthe;
+pub mod wyrand;
 pub mod xoroshiro;
 pub mod xorshift;
 pub mod xoshiro;
 
+pub use chacha::*;
 pub use jsf::*;
+pub use kiss::*;
 pub use lcg::*;
+pub use lehmer::*;
+pub use mersenne_twister::*;
 pub use middle_square::*;
+pub use mrg::*;
+pub use mwc::*;
+pub use random123::*;
+pub use romu::*;
 pub use scf32::*;
 pub use splitmix::*;
+pub use squares::*;
 pub use step_generators::*;
+pub use tausworthe::*;
+pub use wyrand::*;
 pub use xoroshiro::*;
 pub use xorshift::*;
 pub use xoshiro::*;
@@ -23,9 +45,13 @@ pub use xoshiro::*;
 use crate::{AlgorithmOutput, PRNG};
 
 ///A macro that will write the streaming building for us
+///
+/// Generators that need to be run a few rounds before their outputs are random, like the chaotic generators, pass
+/// ``warm_up: rounds``. This adds a ``warm_up`` method that discards that many outputs, which ``Default`` and
+/// ``From`` call after filling the state, so reference seeding functions can share it.
 #[macro_export]
 macro_rules! prng_setup {
-    ($algo_type:ty, $algo_name:ident, $data:ident, $data_maker:ident) => {
+    (@impls $algo_type:ty, $algo_name:ident, $data:ident, $data_maker:ident $(, $warm_up:ident)?) => {
         ///Implements Iterator for the PRNG
         impl Iterator for $algo_type {
             type Item = <$algo_type as Algorithm>::Output;
@@ -43,6 +69,7 @@ macro_rules! prng_setup {
                 $algo_name {
                     $data: $data_maker(prng),
                 }
+                $(.$warm_up())?
             }
         }
         impl<T: AlgorithmOutput> From<T> for $algo_type {
@@ -53,9 +80,27 @@ macro_rules! prng_setup {
                 $algo_name {
                     $data: $data_maker(prng),
                 }
+                $(.$warm_up())?
             }
         }
     };
+    ($algo_type:ty, $algo_name:ident, $data:ident, $data_maker:ident) => {
+        $crate::prng_setup! {@impls $algo_type, $algo_name, $data, $data_maker}
+    };
+    ($algo_type:ty, $algo_name:ident, $data:ident, $data_maker:ident, warm_up: $rounds:expr) => {
+        impl $algo_type {
+            /// Discards the first outputs, which are still correlated with the seed
+            #[must_use]
+            fn warm_up(mut self) -> Self {
+                for _ in 0..$rounds {
+                    self.gen();
+                }
+                self
+            }
+        }
+
+        $crate::prng_setup! {@impls $algo_type, $algo_name, $data, $data_maker, warm_up}
+    };
 }
 
 ///makes a data streamer from using some input data
@@ -102,6 +147,12 @@ pub fn make_4_u32(mut prng: PRNG<SplitMix64>) -> [u32; 4] {
         prng.gen_u32(),
     ]
 }
+
+///makes 8 u32
+pub fn make_8_u32(mut prng: PRNG<SplitMix64>) -> [u32; 8] {
+    core::array::from_fn(|_| prng.gen_u32())
+}
+
 ///makes 1 u64
 pub fn make_1_u64(mut prng: PRNG<SplitMix64>) -> u64 {
     prng.gen_u64()
@@ -112,6 +163,11 @@ pub fn make_2_u64(mut prng: PRNG<SplitMix64>) -> [u64; 2] {
     [prng.gen_u64(), prng.gen_u64()]
 }
 
+///makes 3 u64
+pub fn make_3_u64(mut prng: PRNG<SplitMix64>) -> [u64; 3] {
+    [prng.gen_u64(), prng.gen_u64(), prng.gen_u64()]
+}
+
 ///makes 4 u64
 pub fn make_4_u64(mut prng: PRNG<SplitMix64>) -> [u64; 4] {
     [
@@ -121,6 +177,16 @@ pub fn make_4_u64(mut prng: PRNG<SplitMix64>) -> [u64; 4] {
         prng.gen_u64(),
     ]
 }
+///makes 8 u64
+pub fn make_8_u64(mut prng: PRNG<SplitMix64>) -> [u64; 8] {
+    core::array::from_fn(|_| prng.gen_u64())
+}
+
+///makes 16 u64
+pub fn make_16_u64(mut prng: PRNG<SplitMix64>) -> [u64; 16] {
+    core::array::from_fn(|_| prng.gen_u64())
+}
+
 /// makes a u128
 pub fn make_1_u128(mut prng: PRNG<SplitMix64>) -> u128 {
     prng.gen_u128()
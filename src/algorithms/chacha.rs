@@ -0,0 +1,266 @@
+//! This module implements the `ChaCha` stream cipher as a cryptographically secure generator
+
+use crate::crypto::CryptoAlgorithm;
+use crate::smol_core::Algorithm;
+use crate::{make_8_u32, make_default_stream, make_stream, AlgorithmOutput, PRNG};
+
+/// The "expand 32-byte k" constant of the first row of the state
+const CONSTANTS: [u32; 4] = [0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574];
+
+/// The number of ``u32`` words in a block
+const BLOCK_WORDS: usize = 16;
+
+/// This is the struct definition of the `ChaCha` stream cipher with ``ROUNDS`` rounds used as a generator.
+///
+/// The state is a 256 bit key, a 64 bit stream id in words 14 and 15, and a 64 bit block counter in words 12 and
+/// 13. The outputs are the words of the keystream, so the stream of outputs in little endian byte order is the
+/// `ChaCha` keystream, and the generator can seek to any word of any stream in constant time.
+///
+/// Unlike the other algorithms the outputs of `ChaCha` can not be predicted from previous outputs, so it can be used
+/// where an adversary might see them. Seeding from a small integer with `From` or `Default` is for reproducibility
+/// only, so `ChaCha` is not a `CryptoAlgorithm`; secrets need a key from a secure source given to `SecureChaCha::new`.
+///
+/// ```rust
+/// use smolprng::{ChaCha20, PRNG};
+///
+/// let mut chacha = ChaCha20::new([7u8; 32], 0);
+/// chacha.set_stream(3);
+/// chacha.set_word_pos(1 << 20);
+///
+/// let mut prng = PRNG{generator: chacha};
+/// let x = prng.gen_u64();
+/// ```
+///
+/// The rounds are applied in pairs, so an odd number of rounds does not compile.
+///
+/// ```compile_fail
+/// use smolprng::ChaCha;
+///
+/// let chacha = ChaCha::<7>::new([7u8; 32], 0);
+/// ```
+pub struct ChaCha<const ROUNDS: usize> {
+    pub(crate) key: [u32; 8],
+    pub(crate) stream: u64,
+    /// The counter of the next block to be generated
+    pub(crate) counter: u64,
+    pub(crate) buffer: [u32; BLOCK_WORDS],
+    pub(crate) index: usize,
+}
+
+/// `ChaCha` with 8 rounds, the fastest variant with no known practical attack
+pub type ChaCha8 = ChaCha<8>;
+
+/// `ChaCha` with 12 rounds
+pub type ChaCha12 = ChaCha<12>;
+
+/// `ChaCha` with 20 rounds, as specified in RFC 7539
+pub type ChaCha20 = ChaCha<20>;
+
+impl<const ROUNDS: usize> ChaCha<ROUNDS> {
+    /// Constructs the generator from a 256 bit key and a stream id, starting at the first word of the stream
+    pub fn new(key: [u8; 32], stream: u64) -> Self {
+        let mut words = [0u32; 8];
+        for (word, bytes) in words.iter_mut().zip(key.chunks_exact(4)) {
+            *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        }
+        Self::from_key_words(words, stream)
+    }
+
+    // is_multiple_of would need Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    const fn from_key_words(key: [u32; 8], stream: u64) -> Self {
+        // every constructor goes through here, the rounds are applied in pairs of a column and a diagonal round
+        const { assert!(ROUNDS % 2 == 0, "ChaCha needs an even number of rounds") };
+        ChaCha {
+            key,
+            stream,
+            counter: 0,
+            buffer: [0; BLOCK_WORDS],
+            index: BLOCK_WORDS,
+        }
+    }
+
+    /// The stream id
+    pub const fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// Switches to another stream, keeping the word position
+    pub fn set_stream(&mut self, stream: u64) {
+        let word_pos = self.word_pos();
+        self.stream = stream;
+        self.set_word_pos(word_pos);
+    }
+
+    /// The position in the stream of the next output, counted in ``u32`` words
+    ///
+    /// The position is 68 bits wide, a 64 bit block counter followed by the index of the word in the block
+    pub const fn word_pos(&self) -> u128 {
+        // the buffer holds block counter - 1, unless nothing has been generated yet
+        (((self.counter as u128) << 4) + self.index as u128).wrapping_sub(BLOCK_WORDS as u128)
+            & ((1 << 68) - 1)
+    }
+
+    /// Seeks to a position in the stream counted in ``u32`` words, only the low 68 bits are used
+    pub fn set_word_pos(&mut self, word_pos: u128) {
+        // truncation to the 64 bit block counter is intended
+        #[allow(clippy::cast_possible_truncation)]
+        let block = (word_pos >> 4) as u64;
+        // the index is less than 16
+        #[allow(clippy::cast_possible_truncation)]
+        let index = (word_pos & 0xf) as usize;
+
+        self.counter = block;
+        self.index = BLOCK_WORDS;
+        if index != 0 {
+            self.refill();
+            self.index = index;
+        }
+    }
+
+    /// Generates the block of the current counter into the buffer and advances the counter
+    fn refill(&mut self) {
+        let mut input = [0u32; BLOCK_WORDS];
+        input[..4].copy_from_slice(&CONSTANTS);
+        input[4..12].copy_from_slice(&self.key);
+        input[12] = self.counter as u32;
+        input[13] = (self.counter >> 32) as u32;
+        input[14] = self.stream as u32;
+        input[15] = (self.stream >> 32) as u32;
+
+        let mut x = input;
+        for _ in 0..ROUNDS / 2 {
+            // column round
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            // diagonal round
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+
+        for ((out, x), input) in self.buffer.iter_mut().zip(x).zip(input) {
+            *out = x.wrapping_add(input);
+        }
+
+        self.counter = self.counter.wrapping_add(1);
+        self.index = 0;
+    }
+}
+
+/// The quarter round on the words at indices a, b, c and d, named as in the RFC
+#[inline(always)]
+#[allow(clippy::many_single_char_names)]
+const fn quarter_round(x: &mut [u32; BLOCK_WORDS], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+impl<const ROUNDS: usize> Algorithm for ChaCha<ROUNDS> {
+    type Output = u32;
+
+    /// Algorithm from "`ChaCha`, a variant of `Salsa20`" - Daniel J. Bernstein 2008, with the block function of
+    /// "`ChaCha20` and `Poly1305` for IETF Protocols" - Yoav Nir, Adam Langley RFC 7539 2015
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        if self.index >= BLOCK_WORDS {
+            self.refill();
+        }
+        let value = self.buffer[self.index];
+        self.index += 1;
+        value
+    }
+}
+
+// prng_setup! expects a single state field, so the same impls are written out here
+
+impl<const ROUNDS: usize> Iterator for ChaCha<ROUNDS> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.gen())
+    }
+}
+
+impl<const ROUNDS: usize> Default for ChaCha<ROUNDS> {
+    fn default() -> Self {
+        let prng = PRNG {
+            generator: make_default_stream(),
+        };
+        Self::from_key_words(make_8_u32(prng), 0)
+    }
+}
+
+impl<const ROUNDS: usize, T: AlgorithmOutput> From<T> for ChaCha<ROUNDS> {
+    fn from(value: T) -> Self {
+        let prng = PRNG {
+            generator: make_stream::<T>(value),
+        };
+        Self::from_key_words(make_8_u32(prng), 0)
+    }
+}
+
+/// `ChaCha` that can only be constructed from a full 256 bit key, this is the `CryptoAlgorithm` form of `ChaCha`
+///
+/// There is no `Default` or `From` seeding, so a secret can not be derived from a small public seed by accident. The
+/// outputs are the same as those of `ChaCha::new` with the same key and stream.
+///
+/// ```rust
+/// use smolprng::{SecureChaCha20, PRNG};
+///
+/// // the key must come from a secure source, such as the operating system
+/// let mut prng = PRNG{generator: SecureChaCha20::new([42u8; 32], 0)};
+/// let key: [u8; 32] = prng.gen_token_bytes();
+/// ```
+pub struct SecureChaCha<const ROUNDS: usize> {
+    pub(crate) chacha: ChaCha<ROUNDS>,
+}
+
+/// `SecureChaCha` with 8 rounds
+pub type SecureChaCha8 = SecureChaCha<8>;
+
+/// `SecureChaCha` with 12 rounds
+pub type SecureChaCha12 = SecureChaCha<12>;
+
+/// `SecureChaCha` with 20 rounds, as specified in RFC 7539
+pub type SecureChaCha20 = SecureChaCha<20>;
+
+impl<const ROUNDS: usize> SecureChaCha<ROUNDS> {
+    /// Constructs the generator from a secret 256 bit key and a stream id, starting at the first word of the stream
+    pub fn new(key: [u8; 32], stream: u64) -> Self {
+        Self {
+            chacha: ChaCha::new(key, stream),
+        }
+    }
+}
+
+impl<const ROUNDS: usize> Iterator for SecureChaCha<ROUNDS> {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.gen())
+    }
+}
+
+impl<const ROUNDS: usize> Algorithm for SecureChaCha<ROUNDS> {
+    type Output = u32;
+
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        self.chacha.gen()
+    }
+}
+
+// ChaCha is only secure with at least 8 rounds, so the reduced round variants are not marked
+impl CryptoAlgorithm for SecureChaCha8 {}
+impl CryptoAlgorithm for SecureChaCha12 {}
+impl CryptoAlgorithm for SecureChaCha20 {}
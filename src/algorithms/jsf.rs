@@ -1,7 +1,12 @@
 //! This module impliments JSF type algorithms
 
 use crate::smol_core::Algorithm;
-use crate::{make_4_u64, make_default_stream, make_stream, prng_setup, AlgorithmOutput, PRNG};
+use crate::{
+    make_4_u32, make_4_u64, make_default_stream, make_stream, prng_setup, AlgorithmOutput, PRNG,
+};
+
+/// The number of outputs Jenkins' ``raninit`` discards, before them the outputs are still correlated with the seed
+const WARM_UP: usize = 20;
 
 /// This is the simple struct definition for the 64 Bit JSF Algorithm originally proposed by Bob Jenkins
 
@@ -9,11 +14,43 @@ pub struct JsfLarge {
     pub(crate) data: [u64; 4],
 }
 
-prng_setup! {JsfLarge, JsfLarge,data, make_4_u64}
+prng_setup! {JsfLarge, JsfLarge,data, make_4_u64, warm_up: WARM_UP}
+
+impl JsfLarge {
+    /// Seeds the generator as ``raninit`` of the reference, including the warm up
+    pub fn from_seed(seed: u64) -> Self {
+        JsfLarge {
+            data: [0xf1ea_5eed, seed, seed, seed],
+        }
+        .warm_up()
+    }
+}
+
+/// This is the simple struct definition for the 32 Bit JSF Algorithm originally proposed by Bob Jenkins, the version
+/// with rotations 27 and 17
+pub struct JsfSmall {
+    pub(crate) data: [u32; 4],
+}
+
+prng_setup! {JsfSmall, JsfSmall,data, make_4_u32, warm_up: WARM_UP}
+
+impl JsfSmall {
+    /// Seeds the generator as ``raninit`` of the reference, including the warm up
+    pub fn from_seed(seed: u32) -> Self {
+        JsfSmall {
+            data: [0xf1ea_5eed, seed, seed, seed],
+        }
+        .warm_up()
+    }
+}
 
 impl Algorithm for JsfLarge {
     type Output = u64;
 
+    /// Revision 2 corrects the updates of ``b`` and ``c``, which used ``b`` in place of ``c`` and ``b`` in place of
+    /// ``d``, and revision 3 discards the first 20 outputs after seeding with `Default` and `From`
+    const REVISION: u32 = 3;
+
     /// Translated from original C Source that can be found [here](https://burtleburtle.net/bob/rand/smallprng.html).
     ///
     /// A copy of the original included here for preservation and verification of correctness.
@@ -39,10 +76,41 @@ impl Algorithm for JsfLarge {
 
         let e = self.data[0].overflowing_sub(self.data[1].rotate_left(7)).0;
         self.data[0] = self.data[1] ^ self.data[2].rotate_left(13);
-        self.data[1] = self.data[1].overflowing_add(self.data[3].rotate_left(37)).0;
-        self.data[2] = self.data[1].overflowing_add(e).0;
+        self.data[1] = self.data[2].overflowing_add(self.data[3].rotate_left(37)).0;
+        self.data[2] = self.data[3].overflowing_add(e).0;
         self.data[3] = e.overflowing_add(self.data[0]).0;
 
         self.data[3]
     }
 }
+
+impl Algorithm for JsfSmall {
+    type Output = u32;
+
+    /// Translated from original C Source that can be found [here](https://burtleburtle.net/bob/rand/smallprng.html).
+    ///
+    ///```C
+    ///typedef unsigned long int u4;
+    ///typedef struct ranctx { u4 a; u4 b; u4 c; u4 d; } ranctx;
+    ///
+    /// #define rot(x,k) (((x)<<(k))|((x)>>(32-(k))))
+    /// u4 ranval( ranctx *x ) {
+    ///     u4 e = x->a - rot(x->b, 27);
+    ///     x->a = x->b ^ rot(x->c, 17);
+    ///     x->b = x->c + x->d;
+    ///     x->c = x->d + e;
+    ///     x->d = e + x->a;
+    ///     return x->d;
+    ///}
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> u32 {
+        let e = self.data[0].wrapping_sub(self.data[1].rotate_left(27));
+        self.data[0] = self.data[1] ^ self.data[2].rotate_left(17);
+        self.data[1] = self.data[2].wrapping_add(self.data[3]);
+        self.data[2] = self.data[3].wrapping_add(e);
+        self.data[3] = e.wrapping_add(self.data[0]);
+
+        self.data[3]
+    }
+}
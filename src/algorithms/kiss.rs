@@ -0,0 +1,54 @@
+//! This module implements Marsaglia's ``KISS99``, the "keep it simple stupid" combination of two multiply with
+//! carry generators, a xorshift and a linear congruential generator
+//!
+//! It is here for reproducing codes that use it, the modern generators are both faster and better. The multiply
+//! with carry halves and the xorshift each have fixed points that seeding through `SplitMix64` avoids.
+//!
+//! Algorithm from George Marsaglia's post "Random numbers for C: End, at last?" to sci.stat.math in 1999
+
+use crate::smol_core::Algorithm;
+use crate::{make_default_stream, make_stream, prng_setup, AlgorithmOutput, SplitMix64, PRNG};
+
+/// Simple struct definition of `Kiss99`, with 128 bits of state and period about 2^123
+pub struct Kiss99 {
+    pub(crate) data: [u32; 4],
+}
+
+/// Makes the state of ``Kiss99``, the multiply with carry words are kept strictly between zero and their fixed
+/// point at ``a * 2^16 - 1`` and the xorshift word is nonzero
+fn make_kiss_state(mut prng: PRNG<SplitMix64>) -> [u32; 4] {
+    let z = 1 + prng.gen_u64_in_range(36969 * 65536 - 2) as u32;
+    let w = 1 + prng.gen_u64_in_range(18000 * 65536 - 2) as u32;
+    let jsr = 1 + prng.gen_u64_in_range(u64::from(u32::MAX)) as u32;
+    [z, w, jsr, prng.gen_u32()]
+}
+
+prng_setup! {Kiss99, Kiss99,data, make_kiss_state}
+
+impl Algorithm for Kiss99 {
+    type Output = u32;
+
+    /// Translated from the C macros of Marsaglia's post, the state is ``[z, w, jsr, jcong]``
+    ///
+    ///```C
+    /// #define znew (z=36969*(z&65535)+(z>>16))
+    /// #define wnew (w=18000*(w&65535)+(w>>16))
+    /// #define MWC ((znew<<16)+wnew )
+    /// #define SHR3 (jsr^=(jsr<<17), jsr^=(jsr>>13), jsr^=(jsr<<5))
+    /// #define CONG (jcong=69069*jcong+1234567)
+    /// #define KISS ((MWC^CONG)+SHR3)
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let [z, w, mut jsr, jcong] = self.data;
+        let z = 36969 * (z & 65535) + (z >> 16);
+        let w = 18000 * (w & 65535) + (w >> 16);
+        let mwc = (z << 16).wrapping_add(w);
+        jsr ^= jsr << 17;
+        jsr ^= jsr >> 13;
+        jsr ^= jsr << 5;
+        let jcong = jcong.wrapping_mul(69069).wrapping_add(1_234_567);
+        self.data = [z, w, jsr, jcong];
+        (mwc ^ jcong).wrapping_add(jsr)
+    }
+}
@@ -0,0 +1,40 @@
+//! This module implements Lemire's `Lehmer64`, a multiplicative congruential generator with 128 bits of state
+
+use crate::smol_core::Algorithm;
+use crate::{
+    make_1_u128, make_default_stream, make_stream, prng_setup, AlgorithmOutput, SplitMix64, PRNG,
+};
+
+/// Simple struct definition of the `Lehmer64` Algorithm, a 128 bit multiplicative congruential generator
+///
+/// The outputs are the high 64 bits of the state. It is very fast on 64 bit targets and passes ``BigCrush``, the
+/// period is 2^126 for an odd state
+pub struct Lehmer64 {
+    pub(crate) data: u128,
+}
+
+/// Makes an odd u128, the state of a multiplicative congruential generator must be odd for the full period
+fn make_odd_u128(prng: PRNG<SplitMix64>) -> u128 {
+    make_1_u128(prng) | 1
+}
+
+prng_setup! {Lehmer64, Lehmer64,data, make_odd_u128}
+
+impl Algorithm for Lehmer64 {
+    type Output = u64;
+
+    /// Translated from the C source that can be found
+    /// [here](https://github.com/lemire/testingRNG/blob/master/source/lehmer64.h)
+    ///
+    ///```C
+    /// static inline uint64_t lehmer64() {
+    ///     g_lehmer64_state *= 0xda942042e4dd58b5;
+    ///     return g_lehmer64_state >> 64;
+    /// }
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        self.data = self.data.wrapping_mul(0xda94_2042_e4dd_58b5);
+        (self.data >> 64) as u64
+    }
+}
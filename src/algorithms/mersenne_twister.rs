@@ -0,0 +1,856 @@
+//! This module implements the 32 and 64 bit Mersenne twisters, for reproducing results of other software
+//!
+//! ``Mt19937`` is the generator of C++ ``std::mt19937``, ``NumPy``'s legacy ``RandomState``, Python's ``random``
+//! and `MATLAB`'s default, and ``Mt19937_64`` is the generator of C++ ``std::mt19937_64``. Both are large, slowish
+//! and fail the linear complexity tests of ``BigCrush``, so they are here for compatibility rather than as a
+//! recommendation.
+//!
+//! Algorithm from "Mersenne twister: a 623-dimensionally equidistributed uniform pseudo-random number generator" -
+//! Makoto Matsumoto, Takuji Nishimura ACM Transactions on Modeling and Computer Simulation 1998
+//! DOI: <https://doi.org/10.1145/272991.272995/>
+//!
+//! ```rust
+//! use smolprng::Mt19937;
+//!
+//! // the same outputs as a default constructed std::mt19937
+//! let mut mt = Mt19937::new(5489);
+//!
+//! // the same outputs as Python's random.seed(0x456_00000345_00000234_00000123)
+//! let mut mt = Mt19937::from_array(&[0x123, 0x234, 0x345, 0x456]);
+//! ```
+
+use crate::gf2::{x_pow_mod, Gf2Poly};
+use crate::smol_core::Algorithm;
+use crate::{make_4_u64, make_8_u32, make_stream, AlgorithmOutput, PRNG};
+
+/// The dimension of the state of both twisters, the degree of their characteristic polynomial
+const DEGREE: usize = 19937;
+
+macro_rules! mersenne_twister {
+    (
+        $(#[$attr:meta])*
+        $name:ident, $word:ty, $n:expr, $m:expr, $matrix:expr, $upper:expr,
+        init: ($init_mul:expr, $init_shift:expr), array: ($array_mul1:expr, $array_mul2:expr),
+        temper: |$y:ident| $temper:block, $data_maker:ident, $polynomial:ident
+    ) => {
+        $(#[$attr])*
+        pub struct $name {
+            pub(crate) state: [$word; $n],
+            /// The index of the next word of the state to be output, the state is twisted when it reaches N
+            pub(crate) index: usize,
+        }
+
+        impl $name {
+            const N: usize = $n;
+            const M: usize = $m;
+
+            /// Seeds the generator as ``init_genrand`` of the reference implementation
+            pub fn new(seed: $word) -> Self {
+                let mut state = [0; $n];
+                state[0] = seed;
+                for i in 1..Self::N {
+                    let previous = state[i - 1];
+                    state[i] = ($init_mul as $word)
+                        .wrapping_mul(previous ^ (previous >> $init_shift))
+                        .wrapping_add(i as $word);
+                }
+                Self { state, index: Self::N }
+            }
+
+            /// Seeds the generator from a key of any length as ``init_by_array`` of the reference implementation
+            ///
+            /// # Panics
+            ///
+            /// Panics if the key is empty
+            pub fn from_array(key: &[$word]) -> Self {
+                assert!(!key.is_empty(), "the key must not be empty");
+
+                let mut generator = Self::new(19_650_218);
+                let state = &mut generator.state;
+                let mix = |state: &[$word; $n], i: usize, mul: $word| {
+                    let previous = state[i - 1];
+                    state[i] ^ (previous ^ (previous >> $init_shift)).wrapping_mul(mul)
+                };
+
+                let (mut i, mut j) = (1, 0);
+                for _ in 0..Self::N.max(key.len()) {
+                    state[i] = mix(state, i, $array_mul1)
+                        .wrapping_add(key[j])
+                        .wrapping_add(j as $word);
+                    i += 1;
+                    j += 1;
+                    if i >= Self::N {
+                        state[0] = state[Self::N - 1];
+                        i = 1;
+                    }
+                    if j >= key.len() {
+                        j = 0;
+                    }
+                }
+                for _ in 1..Self::N {
+                    state[i] = mix(state, i, $array_mul2).wrapping_sub(i as $word);
+                    i += 1;
+                    if i >= Self::N {
+                        state[0] = state[Self::N - 1];
+                        i = 1;
+                    }
+                }
+
+                state[0] = 1 << (<$word>::BITS - 1);
+                generator
+            }
+
+            /// The new word of the recurrence from the oldest word, the one after it and the one M after it
+            #[inline(always)]
+            const fn twist_word(oldest: $word, next: $word, middle: $word) -> $word {
+                let x = (oldest & $upper) | (next & !$upper);
+                middle ^ (x >> 1) ^ ((x & 1).wrapping_neg() & $matrix)
+            }
+
+            /// Replaces the state with the next N words of the recurrence, split where the indices wrap around
+            fn twist(&mut self) {
+                let (n, m) = (Self::N, Self::M);
+                let state = &mut self.state;
+                for i in 0..n - m {
+                    state[i] = Self::twist_word(state[i], state[i + 1], state[i + m]);
+                }
+                for i in n - m..n - 1 {
+                    state[i] = Self::twist_word(state[i], state[i + 1], state[i + m - n]);
+                }
+                state[n - 1] = Self::twist_word(state[n - 1], state[0], state[m - 1]);
+            }
+
+            /// Jumps the generator ahead by ``steps`` outputs, as if ``gen`` had been called ``steps`` times
+            ///
+            /// The jump computes ``x^steps`` modulo the precomputed characteristic polynomial of the generator, so
+            /// it allocates and takes from a few to about a hundred milliseconds, growing with the number of bits of
+            /// ``steps``. Stepping is faster for jumps of less than a few million outputs.
+            pub fn jump(&mut self, steps: u128) {
+                let jump = x_pow_mod(steps, &Gf2Poly::from_words(&$polynomial));
+
+                // Horner's rule on the state as a sliding window of the recurrence, the first word is always
+                // already output so only its top bit is part of the state
+                let mut window = [0; $n];
+                let mut start = 0;
+                for i in (0..=jump.degree().unwrap_or(0)).rev() {
+                    window[start] = Self::twist_word(
+                        window[start],
+                        window[(start + 1) % Self::N],
+                        window[(start + Self::M) % Self::N],
+                    );
+                    start = (start + 1) % Self::N;
+
+                    if jump.coefficient(i) {
+                        let (tail, head) = window.split_at_mut(start);
+                        for (word, value) in head.iter_mut().chain(tail).zip(&self.state) {
+                            *word ^= value;
+                        }
+                    }
+                }
+                window.rotate_left(start);
+                self.state = window;
+            }
+        }
+
+        impl Algorithm for $name {
+            type Output = $word;
+
+            #[inline(always)]
+            fn gen(&mut self) -> Self::Output {
+                if self.index >= Self::N {
+                    self.twist();
+                    self.index = 0;
+                }
+                let $y = self.state[self.index];
+                self.index += 1;
+                $temper
+            }
+        }
+
+        impl Iterator for $name {
+            type Item = $word;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                Some(self.gen())
+            }
+        }
+
+        /// The default seed of the reference implementation and the C++ standard library, 5489
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new(5489)
+            }
+        }
+
+        impl<T: AlgorithmOutput> From<T> for $name {
+            fn from(value: T) -> Self {
+                let prng = PRNG {
+                    generator: make_stream::<T>(value),
+                };
+                Self::from_array(&$data_maker(prng))
+            }
+        }
+    };
+}
+
+mersenne_twister! {
+    /// This is the struct definition of the 32 bit Mersenne twister MT19937
+    Mt19937, u32, 624, 397, 0x9908_b0df, 0x8000_0000,
+    init: (1_812_433_253, 30), array: (1_664_525, 1_566_083_941),
+    temper: |y| {
+        let mut y = y;
+        y ^= y >> 11;
+        y ^= (y << 7) & 0x9d2c_5680;
+        y ^= (y << 15) & 0xefc6_0000;
+        y ^ (y >> 18)
+    }, make_8_u32, MT19937_POLYNOMIAL
+}
+
+mersenne_twister! {
+    /// This is the struct definition of the 64 bit Mersenne twister MT19937-64
+    #[allow(non_camel_case_types)]
+    Mt19937_64, u64, 312, 156, 0xb502_6f5a_a966_19e9, 0xffff_ffff_8000_0000,
+    init: (6_364_136_223_846_793_005_u64, 62),
+    array: (3_935_559_000_370_003_845_u64, 2_862_933_555_777_941_757_u64),
+    temper: |y| {
+        let mut y = y;
+        y ^= (y >> 29) & 0x5555_5555_5555_5555;
+        y ^= (y << 17) & 0x71d6_7fff_eda6_0000;
+        y ^= (y << 37) & 0xfff7_eee0_0000_0000;
+        y ^ (y >> 43)
+    }, make_4_u64, MT19937_64_POLYNOMIAL
+}
+
+/// The characteristic polynomial of ``Mt19937``, bit ``i`` of the words is the coefficient of ``x^i``
+///
+/// It has degree ``DEGREE`` and is the minimal polynomial of the low output bits, found by Berlekamp-Massey.
+pub(crate) const MT19937_POLYNOMIAL: [u64; DEGREE / 64 + 1] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0020_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0100,
+    0x0000_0000_0000_0000,
+    0x0002_0000_0000_0000,
+    0x0000_0800_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_4000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x2000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0020_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0100_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0800_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x4000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0002_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0010,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0080_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0400,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0002_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_2000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0002,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0200_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0200_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0020_0000_0000_0000,
+    0x0000_0020_0000_0000,
+    0x0000_0000_8000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0100,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0800_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_4000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0002_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_2000_0000_0000,
+    0x0002_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0001_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0020,
+    0x0000_0000_0000_0200,
+    0x0000_0000_0000_0000,
+    0x0000_0100_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0002_0000,
+    0x0000_0000_0020_0800,
+    0x0000_0000_0000_8000,
+    0x0200_0000_0000_0000,
+    0x0100_4000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_2000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0800_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0021,
+    0x4000_0000_0000_0000,
+    0x0000_0200_0000_0000,
+    0x0000_0100_0000_0000,
+    0x0000_0000_2000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0020_0000_0000_0000,
+    0x0000_8000_0000_0000,
+    0x0000_0200_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_2100_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_1000,
+    0x0800_0000_0000_0002,
+    0x0020_0000_0000_0001,
+    0x0000_0000_0000_0000,
+    0x0000_0200_0000_0000,
+    0x0000_0008_4000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0002_0000,
+    0x0800_0000_0000_0042,
+    0x0020_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0010_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_2100_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0080,
+    0x0000_0000_0000_0002,
+    0x0020_0000_0000_0001,
+    0x0000_0400_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_2000,
+    0x0000_0000_0000_0080,
+    0x0000_0000_0000_0002,
+    0x0021_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0010_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0108_0000,
+    0x0000_0000_0000_2000,
+    0x0000_0000_0000_0000,
+    0x0840_0000_0000_0002,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0200_0000_0000,
+    0x0000_0000_4200_0000,
+    0x0000_0000_0008_0000,
+    0x0000_0000_0000_2000,
+    0x1000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0021_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_8000_0000,
+    0x0000_0000_0200_0000,
+    0x0000_0000_0100_0000,
+    0x0000_0000_0000_2000,
+    0x0000_0000_0000_0004,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0020_0000_0000,
+    0x0000_0000_8000_0000,
+    0x0000_0000_0200_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_2100,
+    0x1000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0001_0800_0000_0000,
+    0x0000_0020_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0200_0000,
+    0x0000_0000_0008_4000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0042_0000_0000_0000,
+    0x0000_0800_0000_0000,
+    0x0000_0020_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0010_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0100,
+    0x0080_0000_0000_0000,
+    0x0002_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0020_0000_0000,
+    0x0000_0000_0400_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x2000_0000_0000_0000,
+    0x0080_0000_0000_0000,
+    0x0002_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0001_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0010_0000,
+    0x0000_0000_0000_0000,
+    0x2000_0000_0000_0008,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0040_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0200,
+    0x0000_0000_0000_0008,
+    0x0000_0000_0000_0000,
+    0x0000_1000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_8000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0004_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0002_0000_0000,
+];
+
+/// The characteristic polynomial of ``Mt19937_64``, bit ``i`` of the words is the coefficient of ``x^i``
+pub(crate) const MT19937_64_POLYNOMIAL: [u64; DEGREE / 64 + 1] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0100_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0010_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0010,
+    0x0000_0000_0000_0000,
+    0x0000_0001_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0010_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0001_0000,
+    0x0000_0000_0000_0000,
+    0x0000_1000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_0000,
+    0x0000_0000_1000_0000,
+    0x0000_0000_0000_0000,
+    0x0100_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0001_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_1000,
+    0x0000_0000_0000_0000,
+    0x0000_0100_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0010,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x1000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0100_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0001_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0100,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0080_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0010_0000,
+    0x0000_0000_0000_0000,
+    0x0001_a000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x4000_0000_0000_0000,
+    0x0000_0000_0000_0010,
+    0x0000_0000_0000_0000,
+    0x0000_0001_2400_0000,
+    0x0000_0000_0000_0000,
+    0x1050_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0105_8000,
+    0x0000_0000_0000_0000,
+    0x0000_4000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0001_0480,
+    0x0000_0000_0000_0000,
+    0x0000_0041_0000_0000,
+    0x0000_0000_0000_0000,
+    0x1800_0000_0000_0000,
+    0x0000_0000_0000_0104,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0008_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_1011_0000,
+    0x0000_0000_0000_0000,
+    0x0001_9800_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0010_0004,
+    0x0000_0000_0000_0000,
+    0x0001_0088_6000_0000,
+    0x0000_0000_0000_0000,
+    0x0400_0000_0000_0000,
+    0x0000_0000_0000_1001,
+    0x0000_0000_0000_0000,
+    0x0000_0000_1840_0000,
+    0x0000_0000_0000_0000,
+    0x0000_4000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0008_2600,
+    0x0000_0000_0000_0000,
+    0x0001_0050_0000_0000,
+    0x0000_0000_0000_0000,
+    0x8000_0000_0000_0000,
+    0x0000_0000_0100_1805,
+    0x0000_0000_0000_0000,
+    0x0000_0000_4000_0000,
+    0x0000_0000_0000_0000,
+    0x04a0_0000_0000_0000,
+    0x0000_0000_0001_0008,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0040_0000,
+    0x0000_0000_0000_0000,
+    0x0004_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0040,
+    0x0000_0000_0000_0000,
+    0x0000_0226_0000_0000,
+    0x0000_0000_0000_0001,
+    0x4000_0000_0000_0000,
+    0x0000_0000_0000_0010,
+    0x0000_0000_0000_0000,
+    0x0080_0001_8400_0000,
+    0x0000_0000_0000_0000,
+    0x0040_0000_0000_0000,
+    0x0000_0000_0000_0004,
+    0x0000_0000_0000_0000,
+    0x0000_a000_60a4_0000,
+    0x0000_0000_0000_0000,
+    0x0400_4000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0040_0400,
+    0x0000_0000_0000_0000,
+    0x4000_4040_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_2400_2624,
+    0x0000_0000_0000_0000,
+    0x0050_0050_4000_0000,
+    0x0000_0000_0000_0000,
+    0x8400_0000_0000_0000,
+    0x0000_0000_0005_8005,
+    0x0000_0000_0000_0000,
+    0x0000_4000_4040_0000,
+    0x0000_0000_0000_0000,
+    0x04a4_0000_0000_0000,
+    0x0000_0000_0000_0480,
+    0x0000_0000_0000_0000,
+    0x0000_0041_0040_4000,
+    0x0000_0000_0000_0000,
+    0x1804_0400_0000_0000,
+    0x0000_0000_0000_0004,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0040,
+    0x0000_0000_0000_0000,
+    0x0008_0224_0000_0000,
+    0x0000_0000_0000_0000,
+    0x4000_0000_0000_0000,
+    0x0000_0000_0011_0010,
+    0x0000_0000_0000_0000,
+    0x0001_9801_8400_0000,
+    0x0000_0000_0000_0000,
+    0x0040_0000_0000_0000,
+    0x0000_0000_0000_0004,
+    0x0000_0000_0000_0000,
+    0x0000_0088_60a4_0000,
+    0x0000_0000_0000_0000,
+    0x0400_4000_0000_0000,
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_0000,
+    0x0000_0000_1840_0400,
+    0x0000_0000_0000_0000,
+    0x0000_4040_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0008_2624,
+    0x0000_0000_0000_0000,
+    0x0001_0050_4000_0000,
+    0x0000_0000_0000_0000,
+    0x8400_0000_0000_0000,
+    0x0000_0000_0000_1805,
+    0x0000_0000_0000_0000,
+    0x0000_0000_4040_0000,
+    0x0000_0000_0000_0000,
+    0x04a4_0000_0000_0000,
+    0x0000_0000_0000_0008,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0040_4000,
+    0x0000_0000_0000_0000,
+    0x0004_0400_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0040,
+    0x0000_0000_0000_0000,
+    0x0000_0224_0000_0000,
+    0x0000_0000_0000_0000,
+    0x4000_0000_0000_0000,
+    0x0000_0000_0000_0010,
+    0x0000_0000_0000_0000,
+    0x0000_0001_8400_0000,
+    0x0000_0000_0000_0000,
+    0x0040_0000_0000_0000,
+    0x0000_0000_0000_0004,
+    0x0000_0000_0000_0000,
+    0x0000_0000_60a4_0000,
+    0x0000_0000_0000_0000,
+    0x0400_4000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0040_0400,
+    0x0000_0000_0000_0000,
+    0x0000_4040_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_2624,
+    0x0000_0000_0000_0000,
+    0x0000_0050_4000_0000,
+    0x0000_0000_0000_0000,
+    0x8400_0000_0000_0000,
+    0x0000_0000_0000_0005,
+    0x0000_0000_0000_0000,
+    0x0000_0000_4040_0000,
+    0x0000_0000_0000_0000,
+    0x04a4_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0040_4000,
+    0x0000_0000_0000_0000,
+    0x0004_0400_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0040,
+    0x0000_0000_0000_0000,
+    0x0000_0224_0000_0000,
+    0x0000_0000_0000_0000,
+    0x4000_0000_0000_0000,
+    0x0000_0000_0000_0010,
+    0x0000_0000_0000_0000,
+    0x0000_0001_8400_0000,
+    0x0000_0000_0000_0000,
+    0x0040_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_00a4_0000,
+    0x0000_0000_0000_0000,
+    0x0000_4000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0400,
+    0x0000_0000_0000_0000,
+    0x0000_0040_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0024,
+    0x0000_0000_0000_0000,
+    0x0000_0000_4000_0000,
+    0x0000_0000_0000_0000,
+    0x0400_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0040_0000,
+    0x0000_0000_0000_0000,
+    0x0004_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_4000,
+    0x0000_0000_0000_0000,
+    0x0000_0400_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0000_0000_0000,
+    0x0000_0002_0000_0000,
+];
@@ -16,17 +16,30 @@ prng_setup! {MiddleSquare, MiddleSquare,data, make_4_u64}
 impl Algorithm for MiddleSquare {
     type Output = u32;
 
+    /// Revision 2 returns the low half of the rotated square as in the reference, it returned the high half.
+    /// The Weyl increment is also forced to be odd, as the reference requires
+    const REVISION: u32 = 2;
+
+    /// Translated from the original C source that can be found [here](https://arxiv.org/abs/1704.00358).
+    ///
+    ///```C
+    /// uint64_t x = 0, w = 0, s = 0xb5ad4eceda1ce2a9;
+    ///
+    /// inline static uint32_t msws() {
+    ///     x *= x; x += (w += s); return x = (x>>32) | (x<<32);
+    /// }
+    /// ```
     #[inline(always)]
     fn gen(&mut self) -> Self::Output {
         let mut x = self.data[0];
         let mut w = self.data[1];
-        w = w.overflowing_add(self.data[2]).0;
+        w = w.overflowing_add(self.data[2] | 1).0;
         x = x.overflowing_mul(x).0;
         x = x.overflowing_add(w).0;
 
-        x = (x >> 32) | (x.overflowing_shl(32).0);
+        x = x.rotate_left(32);
         self.data[0] = x;
         self.data[1] = w;
-        (x >> 32) as u32
+        x as u32
     }
 }
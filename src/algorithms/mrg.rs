@@ -0,0 +1,196 @@
+//! This module implements L'Ecuyer's ``MRG32k3a``, a combined multiple recursive generator, with the stream and
+//! substream jumps of his ``RngStreams`` package
+//!
+//! The period of about 2^191 is cut into streams of 2^127 outputs, each cut into substreams of 2^76 outputs, and
+//! the jumps between them are products with precomputed matrices. It is slower than the modern generators but its
+//! lattice structure is well understood, so many simulation codes validate against it.
+//!
+//! Algorithm from "Good Parameters and Implementations for Combined Multiple Recursive Random Number Generators" -
+//! Pierre L'Ecuyer Operations Research 1999
+//! DOI: <https://doi.org/10.1287/opre.47.1.159/>
+//! and "An Object-Oriented Random-Number Package with Many Long Streams and Substreams" - Pierre L'Ecuyer, Richard
+//! Simard, E. Jack Chen, W. David Kelton Operations Research 2002
+//! DOI: <https://doi.org/10.1287/opre.50.6.1073.358/>
+//!
+//! ```rust
+//! use smolprng::Mrg32k3a;
+//!
+//! // the first stream of RngStreams with its default package seed
+//! let mut stream = Mrg32k3a::new([12345; 6]);
+//! // the start of the next substream, as RngStream_ResetNextSubstream
+//! stream.next_substream();
+//! // the start of the second stream, as the second RngStream_CreateStream
+//! stream.next_stream();
+//! ```
+
+use crate::smol_core::Algorithm;
+use crate::{make_default_stream, make_stream, AlgorithmOutput, SplitMix64, PRNG};
+
+/// The modulus of the first component
+const M1: u64 = 4_294_967_087;
+
+/// The modulus of the second component
+const M2: u64 = 4_294_944_443;
+
+/// The 3 by 3 transition matrix of the first component to the power 2^76, the jump to the next substream
+const A1P76: [[u64; 3]; 3] = [
+    [82_758_667, 1_871_391_091, 4_127_413_238],
+    [3_672_831_523, 69_195_019, 1_871_391_091],
+    [3_672_091_415, 3_528_743_235, 69_195_019],
+];
+
+/// The transition matrix of the second component to the power 2^76
+const A2P76: [[u64; 3]; 3] = [
+    [1_511_326_704, 3_759_209_742, 1_610_795_712],
+    [4_292_754_251, 1_511_326_704, 3_889_917_532],
+    [3_859_662_829, 4_292_754_251, 3_708_466_080],
+];
+
+/// The transition matrix of the first component to the power 2^127, the jump to the next stream
+const A1P127: [[u64; 3]; 3] = [
+    [2_427_906_178, 3_580_155_704, 949_770_784],
+    [226_153_695, 1_230_515_664, 3_580_155_704],
+    [1_988_835_001, 986_791_581, 1_230_515_664],
+];
+
+/// The transition matrix of the second component to the power 2^127
+const A2P127: [[u64; 3]; 3] = [
+    [1_464_411_153, 277_697_599, 1_610_723_613],
+    [32_183_930, 1_464_411_153, 1_022_607_788],
+    [2_824_425_944, 32_183_930, 2_093_834_863],
+];
+
+/// Simple struct definition of `Mrg32k3a`, the state is ``[s10, s11, s12, s20, s21, s22]``
+///
+/// The outputs are the integers in ``[1, m1]`` that the reference scales by ``1 / (m1 + 1)``, so the top 208
+/// values of a ``u32`` never occur. The starts of the current stream and substream are kept for the jumps.
+pub struct Mrg32k3a {
+    pub(crate) data: [u32; 6],
+    pub(crate) substream: [u32; 6],
+    pub(crate) stream: [u32; 6],
+}
+
+/// Multiplies the state of each component by its matrix, modulo its modulus
+fn jump_state(state: [u32; 6], first: &[[u64; 3]; 3], second: &[[u64; 3]; 3]) -> [u32; 6] {
+    let product = |matrix: &[[u64; 3]; 3], vector: &[u32], modulus: u64| {
+        matrix.map(|row| {
+            let sum: u128 = row
+                .iter()
+                .zip(vector)
+                .map(|(a, &s)| u128::from(a * u64::from(s)))
+                .sum();
+            (sum % u128::from(modulus)) as u32
+        })
+    };
+    let mut jumped = [0; 6];
+    jumped[..3].copy_from_slice(&product(first, &state[..3], M1));
+    jumped[3..].copy_from_slice(&product(second, &state[3..], M2));
+    jumped
+}
+
+impl Mrg32k3a {
+    /// Makes the generator at the start of the stream with the given seed, like the package seed of ``RngStreams``
+    ///
+    /// # Panics
+    /// Panics if the first three words are not all below ``m1 = 4294967087`` or are all zero, or the same for the
+    /// last three words and ``m2 = 4294944443``
+    #[must_use]
+    pub fn new(seed: [u32; 6]) -> Self {
+        let (first, second) = seed.split_at(3);
+        assert!(
+            first.iter().all(|&s| u64::from(s) < M1) && first.iter().any(|&s| s != 0),
+            "the first three words of the seed must be below m1 and not all zero"
+        );
+        assert!(
+            second.iter().all(|&s| u64::from(s) < M2) && second.iter().any(|&s| s != 0),
+            "the last three words of the seed must be below m2 and not all zero"
+        );
+        Self {
+            data: seed,
+            substream: seed,
+            stream: seed,
+        }
+    }
+
+    /// Moves to the start of the next substream, 2^76 outputs after the start of the current one
+    pub fn next_substream(&mut self) {
+        self.substream = jump_state(self.substream, &A1P76, &A2P76);
+        self.data = self.substream;
+    }
+
+    /// Moves to the start of the next stream, 2^127 outputs after the start of the current one
+    pub fn next_stream(&mut self) {
+        self.stream = jump_state(self.stream, &A1P127, &A2P127);
+        self.substream = self.stream;
+        self.data = self.stream;
+    }
+
+    /// Makes a seed with each component drawn uniformly below its modulus and not all zero
+    fn from_stream(mut prng: PRNG<SplitMix64>) -> Self {
+        let mut seed = [0; 6];
+        for (component, modulus) in seed.chunks_mut(3).zip([M1, M2]) {
+            while component.iter().all(|&s| s == 0) {
+                for word in component.iter_mut() {
+                    *word = prng.gen_u64_in_range(modulus) as u32;
+                }
+            }
+        }
+        Self::new(seed)
+    }
+}
+
+// prng_setup! expects a single state field, so the same impls are written out here
+
+impl Iterator for Mrg32k3a {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.gen())
+    }
+}
+
+impl Default for Mrg32k3a {
+    fn default() -> Self {
+        Self::from_stream(PRNG {
+            generator: make_default_stream(),
+        })
+    }
+}
+
+impl<T: AlgorithmOutput> From<T> for Mrg32k3a {
+    fn from(value: T) -> Self {
+        Self::from_stream(PRNG {
+            generator: make_stream::<T>(value),
+        })
+    }
+}
+
+impl Algorithm for Mrg32k3a {
+    type Output = u32;
+
+    /// Translated from the C source of the 1999 paper, in integer arithmetic
+    ///
+    ///```C
+    /// p1 = a12 * s11 - a13n * s10;
+    /// k = p1 / m1;  p1 -= k * m1;  if (p1 < 0.0) p1 += m1;
+    /// s10 = s11;   s11 = s12;   s12 = p1;
+    /// p2 = a21 * s22 - a23n * s20;
+    /// k  = p2 / m2;  p2 -= k * m2;   if (p2 < 0.0) p2 += m2;
+    /// s20 = s21;   s21 = s22;   s22 = p2;
+    /// if (p1 <= p2) return ((p1 - p2 + m1) * norm);
+    /// else return ((p1 - p2) * norm);
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let [s10, s11, s12, s20, s21, s22] = self.data.map(u64::from);
+        // adding a13n * m1 and a23n * m2 keeps the differences positive
+        let p1 = (1_403_580 * s11 + 810_728 * (M1 - s10)) % M1;
+        let p2 = (527_612 * s22 + 1_370_589 * (M2 - s20)) % M2;
+        self.data = [s11, s12, p1, s21, s22, p2].map(|s| s as u32);
+        if p1 > p2 {
+            (p1 - p2) as u32
+        } else {
+            (p1 + M1 - p2) as u32
+        }
+    }
+}
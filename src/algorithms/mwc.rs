@@ -0,0 +1,244 @@
+//! This module implements Marsaglia's multiply with carry generators, Vigna's modern 64 bit ``Mwc`` family and the
+//! classic complementary ``Cmwc4096``
+//!
+//! A multiply with carry generator of lag ``r`` is a multiplicative congruential generator in disguise, its state
+//! read as the number ``c * 2^(64 r) + x[r-1] * 2^(64 (r-1)) + ... + x[0]`` is multiplied by the inverse of ``2^64``
+//! modulo the prime ``m = a * 2^(64 r) - 1`` at every step. The period is the order of that inverse, about ``m / 2``
+//! for the published multipliers, and ``jump`` is a modular exponentiation.
+//!
+//! The two states that are fixed points, all zero and the state reading as ``m``, are avoided by keeping the carry
+//! strictly between zero and ``a - 1``, which seeding through `SplitMix64` does.
+//!
+//! Algorithms from "A new class of random number generators" - George Marsaglia, Arif Zaman The Annals of Applied
+//! Probability 1991 and the C sources at <https://prng.di.unimi.it/>
+
+use crate::smol_core::Algorithm;
+use crate::{make_default_stream, make_stream, prng_setup, AlgorithmOutput, SplitMix64, PRNG};
+
+/// Numbers modulo an ``m`` of up to 256 bits, as little endian 64 bit limbs with one spare limb for the doubling
+type Limbs = [u64; 5];
+
+/// ``(x + y) mod m`` for ``x`` and ``y`` already reduced
+fn add_mod(x: &Limbs, y: &Limbs, m: &Limbs) -> Limbs {
+    let mut sum = [0; 5];
+    let mut carry = false;
+    for i in 0..5 {
+        let (partial, overflow_x) = x[i].overflowing_add(y[i]);
+        let (limb, overflow_carry) = partial.overflowing_add(u64::from(carry));
+        sum[i] = limb;
+        carry = overflow_x || overflow_carry;
+    }
+
+    // compare from the top limb, and subtract m once if the sum is not below it
+    if sum.iter().rev().cmp(m.iter().rev()).is_ge() {
+        let mut borrow = false;
+        for i in 0..5 {
+            let (partial, underflow_m) = sum[i].overflowing_sub(m[i]);
+            let (limb, underflow_borrow) = partial.overflowing_sub(u64::from(borrow));
+            sum[i] = limb;
+            borrow = underflow_m || underflow_borrow;
+        }
+    }
+    sum
+}
+
+/// ``(x * y) mod m`` by doubling and adding, the jumps only need a few hundred of these
+fn mul_mod(x: &Limbs, y: &Limbs, m: &Limbs) -> Limbs {
+    let mut product = [0; 5];
+    for bit in (0..320).rev() {
+        product = add_mod(&product, &product, m);
+        if y[bit / 64] >> (bit % 64) & 1 == 1 {
+            product = add_mod(&product, x, m);
+        }
+    }
+    product
+}
+
+/// ``base^exponent mod m`` by squaring and multiplying
+fn pow_mod(base: &Limbs, exponent: u128, m: &Limbs) -> Limbs {
+    let mut power = [1, 0, 0, 0, 0];
+    for bit in (0..128).rev() {
+        power = mul_mod(&power, &power, m);
+        if exponent >> bit & 1 == 1 {
+            power = mul_mod(&power, base, m);
+        }
+    }
+    power
+}
+
+macro_rules! mwc {
+    ($(#[$doc:meta])* $algo_name:ident, $lag:literal, $multiplier:literal, $data_maker:ident) => {
+        $(#[$doc])*
+        pub struct $algo_name {
+            pub(crate) data: [u64; $lag + 1],
+        }
+
+        /// Makes a seeded state, the carry is drawn strictly between zero and ``a - 1`` to avoid the fixed points
+        fn $data_maker(mut prng: PRNG<SplitMix64>) -> [u64; $lag + 1] {
+            let mut state = [0; $lag + 1];
+            for word in &mut state[..$lag] {
+                *word = prng.gen_u64();
+            }
+            state[$lag] = 1 + prng.gen_u64_in_range($multiplier - 2);
+            state
+        }
+
+        prng_setup! {$algo_name, $algo_name,data, $data_maker}
+
+        impl $algo_name {
+            /// The multiplier ``a`` of the generator
+            pub const MULTIPLIER: u64 = $multiplier;
+
+            /// Jumps the generator ahead by ``steps`` outputs, as if ``gen`` had been called ``steps`` times
+            ///
+            /// The state is multiplied by ``2^(-64 steps)`` modulo ``m``, so any jump takes about the same time, around
+            /// a millisecond.
+            pub fn jump(&mut self, steps: u128) {
+                // m = a * 2^(64 r) - 1, and the inverse of 2^64 modulo m is a * 2^(64 (r - 1))
+                let mut modulus = [0; 5];
+                modulus[..$lag].fill(u64::MAX);
+                modulus[$lag] = $multiplier - 1;
+                let mut inverse = [0; 5];
+                inverse[$lag - 1] = $multiplier;
+
+                let mut state = [0; 5];
+                state[..=$lag].copy_from_slice(&self.data);
+                let state = mul_mod(&state, &pow_mod(&inverse, steps, &modulus), &modulus);
+                self.data.copy_from_slice(&state[..=$lag]);
+            }
+        }
+
+        impl Algorithm for $algo_name {
+            type Output = u64;
+
+            /// Translated from the C sources that can be found [here](https://prng.di.unimi.it/), the state is
+            /// ``[x, ..., c]`` with the oldest word first and the output is the newest word
+            ///
+            ///```C
+            /// uint64_t inline next() {
+            ///     const uint64_t result = x;
+            ///     const __uint128_t t = MWC_A1 * (__uint128_t)x + c;
+            ///     x = t;
+            ///     c = t >> 64;
+            ///     return result;
+            /// }
+            /// ```
+            #[inline(always)]
+            fn gen(&mut self) -> Self::Output {
+                let data = self.data;
+                let t = u128::from(Self::MULTIPLIER) * u128::from(data[0]) + u128::from(data[$lag]);
+                // the whole state is written at once, shifting in place compiles to overlapping loads and stores
+                // that stall store forwarding and made Mwc256 five times slower
+                self.data = core::array::from_fn(|i| match i {
+                    i if i + 1 < $lag => data[i + 1],
+                    i if i + 1 == $lag => t as u64,
+                    _ => (t >> 64) as u64,
+                });
+                data[$lag - 1]
+            }
+        }
+    };
+}
+
+mwc! {
+    /// Simple struct definition of `Mwc128`, a lag 1 multiply with carry generator with period about 2^127
+    Mwc128, 1, 0xffeb_b71d_94fc_daf9, make_mwc_128_state
+}
+
+mwc! {
+    /// Simple struct definition of `Mwc192`, a lag 2 multiply with carry generator with period about 2^191
+    Mwc192, 2, 0xffa0_4e67_b3c9_5d86, make_mwc_192_state
+}
+
+mwc! {
+    /// Simple struct definition of `Mwc256`, a lag 3 multiply with carry generator with period about 2^255
+    Mwc256, 3, 0xff37_7e26_f82d_a74a, make_mwc_256_state
+}
+
+/// The multiplier of ``Cmwc4096``
+const CMWC_MULTIPLIER: u32 = 18782;
+
+/// Simple struct definition of Marsaglia's `Cmwc4096`, a lag 4096 complementary multiply with carry generator
+///
+/// The period is about 2^131086, so it is for jobs that want an enormous state, and at 16 KiB it is best kept in a
+/// ``Box`` when moved around. There is no jump, the modular exponentiation would be on 131 thousand bit numbers.
+pub struct Cmwc4096 {
+    pub(crate) data: [u32; 4096],
+    pub(crate) carry: u32,
+    pub(crate) index: usize,
+}
+
+impl Cmwc4096 {
+    /// Makes a seeded state, the words are kept below ``2^32 - 1`` as the base of the generator is ``2^32 - 1``
+    /// and the carry is drawn strictly between zero and ``a - 1``
+    fn from_stream(mut prng: PRNG<SplitMix64>) -> Self {
+        let mut data = [0; 4096];
+        for word in &mut data {
+            *word = prng.gen_u64_in_range(u64::from(u32::MAX)) as u32;
+        }
+        let carry = 1 + prng.gen_u64_in_range(u64::from(CMWC_MULTIPLIER) - 2) as u32;
+        Self {
+            data,
+            carry,
+            index: 4095,
+        }
+    }
+}
+
+// prng_setup! expects a single state field, so the same impls are written out here
+
+impl Iterator for Cmwc4096 {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.gen())
+    }
+}
+
+impl Default for Cmwc4096 {
+    fn default() -> Self {
+        Self::from_stream(PRNG {
+            generator: make_default_stream(),
+        })
+    }
+}
+
+impl<T: AlgorithmOutput> From<T> for Cmwc4096 {
+    fn from(value: T) -> Self {
+        Self::from_stream(PRNG {
+            generator: make_stream::<T>(value),
+        })
+    }
+}
+
+impl Algorithm for Cmwc4096 {
+    type Output = u32;
+
+    /// Translated from Marsaglia's C source posted to sci.math in 2003
+    ///
+    ///```C
+    /// unsigned long CMWC4096(void){
+    ///     unsigned long long t, a=18782LL;
+    ///     static unsigned long i=4095;
+    ///     unsigned long x,r=0xfffffffe;
+    ///     i=(i+1)&4095;
+    ///     t=a*Q[i]+c;
+    ///     c=(t>>32); x=t+c; if(x<c){x++;c++;}
+    ///     return(Q[i]=r-x);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        self.index = (self.index + 1) & 4095;
+        let t =
+            u64::from(CMWC_MULTIPLIER) * u64::from(self.data[self.index]) + u64::from(self.carry);
+        self.carry = (t >> 32) as u32;
+        let mut x = (t as u32).wrapping_add(self.carry);
+        if x < self.carry {
+            x += 1;
+            self.carry += 1;
+        }
+        self.data[self.index] = 0xffff_fffe - x;
+        self.data[self.index]
+    }
+}
@@ -0,0 +1,287 @@
+//! This module implements the counter based generators of the Random123 library, Philox and Threefry
+//!
+//! Each output block is a pure function of a key and a counter, so any block of any stream can be computed directly
+//! from its index. A parallel simulation can give each work item its own key, or its own range of counters, and
+//! get the same random numbers however the work is scheduled.
+//!
+//! Algorithms from "Parallel random numbers: as easy as 1, 2, 3" - John K. Salmon, Mark A. Moraes, Ron O. Dror,
+//! David E. Shaw SC11 2011
+//! DOI: <https://doi.org/10.1145/2063384.2063405/>
+//!
+//! ```rust
+//! use smolprng::{philox, Philox4x32_10};
+//!
+//! // the random numbers of work item 1234 in simulation step 7
+//! let block = philox([0xdead_beef, 42], [1234, 7, 0, 0]);
+//!
+//! // or as a generator starting from that counter
+//! let mut generator = Philox4x32_10::new([0xdead_beef, 42]);
+//! generator.seek([1234, 7, 0, 0]);
+//! ```
+
+use crate::smol_core::Algorithm;
+use crate::{make_1_u64, make_4_u64, make_default_stream, make_stream, AlgorithmOutput, PRNG};
+
+const PHILOX_M0: u32 = 0xD251_1F53;
+const PHILOX_M1: u32 = 0xCD9E_8D57;
+const PHILOX_W0: u32 = 0x9E37_79B9;
+const PHILOX_W1: u32 = 0xBB67_AE85;
+
+/// The parity constant of the Threefish key schedule
+const THREEFRY_PARITY: u64 = 0x1BD1_1BDA_A9FC_1A22;
+
+/// The rotations of the Threefish-256 rounds, the pattern repeats every eight rounds
+const THREEFRY_ROTATIONS: [[u32; 2]; 8] = [
+    [14, 16],
+    [52, 57],
+    [23, 40],
+    [5, 37],
+    [25, 33],
+    [46, 12],
+    [58, 22],
+    [32, 32],
+];
+
+/// The Philox4x32 block function with 10 rounds, a bijection of the counter for every key
+pub const fn philox(key: [u32; 2], ctr: [u32; 4]) -> [u32; 4] {
+    let mut key = key;
+    let mut ctr = ctr;
+    let mut round = 0;
+
+    while round < 10 {
+        if round > 0 {
+            key[0] = key[0].wrapping_add(PHILOX_W0);
+            key[1] = key[1].wrapping_add(PHILOX_W1);
+        }
+
+        let p0 = PHILOX_M0 as u64 * ctr[0] as u64;
+        let p1 = PHILOX_M1 as u64 * ctr[2] as u64;
+        ctr = [
+            (p1 >> 32) as u32 ^ ctr[1] ^ key[0],
+            p1 as u32,
+            (p0 >> 32) as u32 ^ ctr[3] ^ key[1],
+            p0 as u32,
+        ];
+        round += 1;
+    }
+
+    ctr
+}
+
+/// The Threefry4x64 block function with 20 rounds, a bijection of the counter for every key
+pub const fn threefry(key: [u64; 4], ctr: [u64; 4]) -> [u64; 4] {
+    let schedule = [
+        key[0],
+        key[1],
+        key[2],
+        key[3],
+        THREEFRY_PARITY ^ key[0] ^ key[1] ^ key[2] ^ key[3],
+    ];
+
+    let mut x = [
+        ctr[0].wrapping_add(schedule[0]),
+        ctr[1].wrapping_add(schedule[1]),
+        ctr[2].wrapping_add(schedule[2]),
+        ctr[3].wrapping_add(schedule[3]),
+    ];
+
+    let mut round = 0;
+    while round < 20 {
+        let [r0, r1] = THREEFRY_ROTATIONS[round % 8];
+        if round % 2 == 0 {
+            x[0] = x[0].wrapping_add(x[1]);
+            x[1] = x[1].rotate_left(r0) ^ x[0];
+            x[2] = x[2].wrapping_add(x[3]);
+            x[3] = x[3].rotate_left(r1) ^ x[2];
+        } else {
+            x[0] = x[0].wrapping_add(x[3]);
+            x[3] = x[3].rotate_left(r0) ^ x[0];
+            x[2] = x[2].wrapping_add(x[1]);
+            x[1] = x[1].rotate_left(r1) ^ x[2];
+        }
+
+        // the key is injected after every fourth round
+        if round % 4 == 3 {
+            let s = (round + 1) / 4;
+            x[0] = x[0].wrapping_add(schedule[s % 5]);
+            x[1] = x[1].wrapping_add(schedule[(s + 1) % 5]);
+            x[2] = x[2].wrapping_add(schedule[(s + 2) % 5]);
+            x[3] = x[3]
+                .wrapping_add(schedule[(s + 3) % 5])
+                .wrapping_add(s as u64);
+        }
+        round += 1;
+    }
+
+    x
+}
+
+/// This is the struct definition of the Philox4x32-10 generator, the outputs are the words of ``philox(key, ctr)``
+/// for successive counters
+///
+/// The counter is a 128 bit integer with ``ctr[0]`` as the lowest word.
+#[allow(non_camel_case_types)]
+pub struct Philox4x32_10 {
+    pub(crate) key: [u32; 2],
+    /// The counter of the next block to be generated
+    pub(crate) ctr: [u32; 4],
+    pub(crate) buffer: [u32; 4],
+    pub(crate) index: usize,
+}
+
+impl Philox4x32_10 {
+    /// Constructs the generator from a key, starting at counter zero
+    pub const fn new(key: [u32; 2]) -> Self {
+        Philox4x32_10 {
+            key,
+            ctr: [0; 4],
+            buffer: [0; 4],
+            index: 4,
+        }
+    }
+
+    /// The counter of the next block to be generated, unless the current block has outputs left
+    pub const fn counter(&self) -> [u32; 4] {
+        self.ctr
+    }
+
+    /// Seeks to the start of the block of `ctr`
+    pub const fn seek(&mut self, ctr: [u32; 4]) {
+        self.ctr = ctr;
+        self.index = 4;
+    }
+}
+
+impl Algorithm for Philox4x32_10 {
+    type Output = u32;
+
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        if self.index >= 4 {
+            self.buffer = philox(self.key, self.ctr);
+            for word in &mut self.ctr {
+                *word = word.wrapping_add(1);
+                if *word != 0 {
+                    break;
+                }
+            }
+            self.index = 0;
+        }
+        let value = self.buffer[self.index];
+        self.index += 1;
+        value
+    }
+}
+
+/// This is the struct definition of the Threefry4x64-20 generator, the outputs are the words of
+/// ``threefry(key, ctr)`` for successive counters
+///
+/// The counter is a 256 bit integer with ``ctr[0]`` as the lowest word.
+#[allow(non_camel_case_types)]
+pub struct Threefry4x64_20 {
+    pub(crate) key: [u64; 4],
+    /// The counter of the next block to be generated
+    pub(crate) ctr: [u64; 4],
+    pub(crate) buffer: [u64; 4],
+    pub(crate) index: usize,
+}
+
+impl Threefry4x64_20 {
+    /// Constructs the generator from a key, starting at counter zero
+    pub const fn new(key: [u64; 4]) -> Self {
+        Threefry4x64_20 {
+            key,
+            ctr: [0; 4],
+            buffer: [0; 4],
+            index: 4,
+        }
+    }
+
+    /// The counter of the next block to be generated, unless the current block has outputs left
+    pub const fn counter(&self) -> [u64; 4] {
+        self.ctr
+    }
+
+    /// Seeks to the start of the block of `ctr`
+    pub const fn seek(&mut self, ctr: [u64; 4]) {
+        self.ctr = ctr;
+        self.index = 4;
+    }
+}
+
+impl Algorithm for Threefry4x64_20 {
+    type Output = u64;
+
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        if self.index >= 4 {
+            self.buffer = threefry(self.key, self.ctr);
+            for word in &mut self.ctr {
+                *word = word.wrapping_add(1);
+                if *word != 0 {
+                    break;
+                }
+            }
+            self.index = 0;
+        }
+        let value = self.buffer[self.index];
+        self.index += 1;
+        value
+    }
+}
+
+// prng_setup! expects a single state field, so the same impls are written out here
+
+impl Iterator for Philox4x32_10 {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.gen())
+    }
+}
+
+impl Default for Philox4x32_10 {
+    fn default() -> Self {
+        let prng = PRNG {
+            generator: make_default_stream(),
+        };
+        let key = make_1_u64(prng);
+        Self::new([key as u32, (key >> 32) as u32])
+    }
+}
+
+impl<T: AlgorithmOutput> From<T> for Philox4x32_10 {
+    fn from(value: T) -> Self {
+        let prng = PRNG {
+            generator: make_stream::<T>(value),
+        };
+        let key = make_1_u64(prng);
+        Self::new([key as u32, (key >> 32) as u32])
+    }
+}
+
+impl Iterator for Threefry4x64_20 {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.gen())
+    }
+}
+
+impl Default for Threefry4x64_20 {
+    fn default() -> Self {
+        let prng = PRNG {
+            generator: make_default_stream(),
+        };
+        Self::new(make_4_u64(prng))
+    }
+}
+
+impl<T: AlgorithmOutput> From<T> for Threefry4x64_20 {
+    fn from(value: T) -> Self {
+        let prng = PRNG {
+            generator: make_stream::<T>(value),
+        };
+        Self::new(make_4_u64(prng))
+    }
+}
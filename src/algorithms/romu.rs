@@ -0,0 +1,112 @@
+//! This module implements Mark Overton's Romu family, nonlinear generators built from a rotation and a multiply
+//!
+//! The state is updated without an additive constant, so the all zero state is a fixed point that seeding through
+//! `SplitMix64` avoids. The period depends on the seed, but with as much state as these have a cycle too short to
+//! matter is astronomically unlikely.
+//!
+//! Algorithms from "Romu: Fast Nonlinear Pseudo-Random Number Generators Providing High Quality" - Mark A. Overton
+//! arXiv 2020
+//! arXiv: <https://arxiv.org/abs/2002.11331/>
+
+use crate::smol_core::Algorithm;
+use crate::{
+    make_2_u64, make_3_u64, make_4_u64, make_default_stream, make_stream, prng_setup,
+    AlgorithmOutput, PRNG,
+};
+
+/// The multiplier shared by the Romu generators
+const ROMU_MULTIPLIER: u64 = 15_241_094_284_759_029_579;
+
+/// Simple struct definition of `RomuQuad`, the most robust of the family with 256 bits of state
+pub struct RomuQuad {
+    pub(crate) data: [u64; 4],
+}
+
+/// Simple struct definition of `RomuTrio`, the recommended general purpose member of the family with 192 bits of
+/// state
+pub struct RomuTrio {
+    pub(crate) data: [u64; 3],
+}
+
+/// Simple struct definition of `RomuDuoJr`, the fastest of the family with 128 bits of state, meant for jobs that
+/// use fewer than 2^51 outputs
+pub struct RomuDuoJr {
+    pub(crate) data: [u64; 2],
+}
+
+prng_setup! {RomuQuad, RomuQuad,data, make_4_u64}
+prng_setup! {RomuTrio, RomuTrio,data, make_3_u64}
+prng_setup! {RomuDuoJr, RomuDuoJr,data, make_2_u64}
+
+impl Algorithm for RomuQuad {
+    type Output = u64;
+
+    /// Translated from the C source of the paper, the state is ``[w, x, y, z]``
+    ///
+    ///```C
+    /// uint64_t wp = wState, xp = xState, yp = yState, zp = zState;
+    /// wState = 15241094284759029579u * zp;
+    /// xState = zp + ROTL(wp,52);
+    /// yState = yp - xp;
+    /// zState = yp + wp;
+    /// zState = ROTL(zState,19);
+    /// return xp;
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let [w, x, y, z] = self.data;
+        self.data = [
+            ROMU_MULTIPLIER.wrapping_mul(z),
+            z.wrapping_add(w.rotate_left(52)),
+            y.wrapping_sub(x),
+            y.wrapping_add(w).rotate_left(19),
+        ];
+        x
+    }
+}
+
+impl Algorithm for RomuTrio {
+    type Output = u64;
+
+    /// Translated from the C source of the paper, the state is ``[x, y, z]``
+    ///
+    ///```C
+    /// uint64_t xp = xState, yp = yState, zp = zState;
+    /// xState = 15241094284759029579u * zp;
+    /// yState = yp - xp;  yState = ROTL(yState,12);
+    /// zState = zp - yp;  zState = ROTL(zState,44);
+    /// return xp;
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let [x, y, z] = self.data;
+        self.data = [
+            ROMU_MULTIPLIER.wrapping_mul(z),
+            y.wrapping_sub(x).rotate_left(12),
+            z.wrapping_sub(y).rotate_left(44),
+        ];
+        x
+    }
+}
+
+impl Algorithm for RomuDuoJr {
+    type Output = u64;
+
+    /// Translated from the C source of the paper, the state is ``[x, y]``
+    ///
+    ///```C
+    /// uint64_t xp = xState;
+    /// xState = 15241094284759029579u * yState;
+    /// yState = yState - xp;  yState = ROTL(yState,27);
+    /// return xp;
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let [x, y] = self.data;
+        self.data = [
+            ROMU_MULTIPLIER.wrapping_mul(y),
+            y.wrapping_sub(x).rotate_left(27),
+        ];
+        x
+    }
+}
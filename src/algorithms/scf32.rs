@@ -1,8 +1,9 @@
-//! This module contains the implementations for the `SFC32` family of PRNG algorithms
+//! This module contains the implementations for the `SFC` family of PRNG algorithms
 
 use crate::smol_core::Algorithm;
 use crate::{
-    make_1_u32, make_4_u32, make_default_stream, make_stream, prng_setup, AlgorithmOutput, PRNG,
+    make_1_u32, make_4_u32, make_4_u64, make_default_stream, make_stream, prng_setup,
+    AlgorithmOutput, PRNG,
 };
 
 /// Simple struct definition for the `SFC32` algorithm
@@ -13,7 +14,38 @@ pub struct Sfc32 {
     pub(crate) data: [u32; 4],
 }
 
-prng_setup! {Sfc32, Sfc32,data, make_4_u32}
+/// The number of outputs `PractRand` discards when seeding, before them the outputs are still correlated with the seed
+const WARM_UP: usize = 12;
+
+prng_setup! {Sfc32, Sfc32,data, make_4_u32, warm_up: WARM_UP}
+
+impl Sfc32 {
+    /// Seeds the generator as ``sfc32::seed`` of `PractRand`, including the warm up
+    pub fn from_seed(seed: u64) -> Self {
+        Sfc32 {
+            data: [0, seed as u32, (seed >> 32) as u32, 1],
+        }
+        .warm_up()
+    }
+}
+
+/// Simple struct definition for the `SFC64` algorithm, the generator `NumPy` offers as ``SFC64``
+/// The original source is part of [`PractRand`](https://pracrand.sourceforge.net/)
+pub struct Sfc64 {
+    pub(crate) data: [u64; 4],
+}
+
+prng_setup! {Sfc64, Sfc64,data, make_4_u64, warm_up: WARM_UP}
+
+impl Sfc64 {
+    /// Seeds the generator as ``sfc64::seed`` of `PractRand`, including the warm up
+    pub fn from_seed(seed: u64) -> Self {
+        Sfc64 {
+            data: [seed, seed, seed, 1],
+        }
+        .warm_up()
+    }
+}
 
 /// Simple struct definition for the `Sfc32Small` algorithm
 /// The translated tree is C -> C++ -> Rust. The original source can be found
@@ -27,16 +59,58 @@ prng_setup! {Sfc32Small, Sfc32Small,data, make_1_u32}
 
 impl Algorithm for Sfc32 {
     type Output = u32;
+
+    /// Revision 2 corrects the algorithm to match `PractRand`, the state is ``[a, b, c, counter]``, and revision 3
+    /// discards the first 12 outputs after seeding with `Default` and `From`
+    const REVISION: u32 = 3;
+
+    /// Translated from the original C++ source in `PractRand`
+    ///
+    ///```C
+    /// Uint32 tmp = a + b + counter++;
+    /// a = b ^ (b >> 9);
+    /// b = c + (c << 3);
+    /// c = ((c << 21) | (c >> 11)) + tmp;
+    /// return tmp;
+    /// ```
     #[inline(always)]
     fn gen(&mut self) -> Self::Output {
+        let t = self.data[0]
+            .overflowing_add(self.data[1])
+            .0
+            .overflowing_add(self.data[3])
+            .0;
         self.data[3] = self.data[3].overflowing_add(1).0;
-        let t = self.data[0].overflowing_add(self.data[1]).0;
-        let t = t.overflowing_add(self.data[2]).0;
-        self.data[0] ^= self.data[1] >> 9;
-        self.data[1] ^= self.data[2].overflowing_shl(3).0;
-        self.data[2] = (self.data[2].overflowing_shl(21).0 | (self.data[1] >> 11))
-            .overflowing_add(t)
+        self.data[0] = self.data[1] ^ (self.data[1] >> 9);
+        self.data[1] = self.data[2]
+            .overflowing_add(self.data[2].overflowing_shl(3).0)
             .0;
+        self.data[2] = self.data[2].rotate_left(21).overflowing_add(t).0;
+        t
+    }
+}
+
+impl Algorithm for Sfc64 {
+    type Output = u64;
+
+    /// Translated from the original C++ source in `PractRand`
+    ///
+    ///```C
+    /// Uint64 tmp = a + b + counter++;
+    /// a = b ^ (b >> 11);
+    /// b = c + (c << 3);
+    /// c = ((c << 24) | (c >> 40)) + tmp;
+    /// return tmp;
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let t = self.data[0]
+            .wrapping_add(self.data[1])
+            .wrapping_add(self.data[3]);
+        self.data[3] = self.data[3].wrapping_add(1);
+        self.data[0] = self.data[1] ^ (self.data[1] >> 11);
+        self.data[1] = self.data[2].wrapping_add(self.data[2] << 3);
+        self.data[2] = self.data[2].rotate_left(24).wrapping_add(t);
         t
     }
 }
@@ -0,0 +1,164 @@
+//! This module implements Widynski's Squares generators, counter based relatives of the middle square Weyl sequence
+//!
+//! The output for a counter is a few rounds of squaring ``ctr * key``, so any output can be computed directly and
+//! every key gives an independent stream. Not every key is a good key, the paper requires the hex digits of the key
+//! to be non zero and distinct within each half, which [`make_squares_key`] guarantees.
+//!
+//! Algorithm from "Squares: A Fast Counter-Based RNG" - Bernard Widynski arXiv 2020
+//! arXiv: <https://arxiv.org/abs/2004.06278/>
+
+use crate::smol_core::Algorithm;
+use crate::{make_default_stream, make_stream, AlgorithmOutput, SplitMix64, PRNG};
+
+/// One round of squares, the square plus an offset with the halves swapped
+const fn squares_round(x: u64, offset: u64) -> u64 {
+    x.wrapping_mul(x).wrapping_add(offset).rotate_left(32)
+}
+
+/// The 32 bit squares output for a counter, four rounds of squaring
+pub const fn squares32(ctr: u64, key: u64) -> u32 {
+    let y = ctr.wrapping_mul(key);
+    let z = y.wrapping_add(key);
+
+    let mut x = squares_round(y, y);
+    x = squares_round(x, z);
+    x = squares_round(x, y);
+    (x.wrapping_mul(x).wrapping_add(z) >> 32) as u32
+}
+
+/// The 64 bit squares output for a counter, five rounds of squaring
+pub const fn squares64(ctr: u64, key: u64) -> u64 {
+    let y = ctr.wrapping_mul(key);
+    let z = y.wrapping_add(key);
+
+    let mut x = squares_round(y, y);
+    x = squares_round(x, z);
+    x = squares_round(x, y);
+    let t = x.wrapping_mul(x).wrapping_add(z);
+    x = t.rotate_left(32);
+    t ^ (x.wrapping_mul(x).wrapping_add(y) >> 32)
+}
+
+/// Checks the rules of the paper for a key, every hex digit is non zero, the digits within each 32 bit half are
+/// distinct and the digits either side of the halves differ
+pub const fn is_valid_squares_key(key: u64) -> bool {
+    let mut half = 0;
+    while half < 2 {
+        let mut seen = 0u16;
+        let mut digit = 0;
+        while digit < 8 {
+            let nibble = (key >> (32 * half + 4 * digit)) & 0xf;
+            if nibble == 0 || seen & (1 << nibble) != 0 {
+                return false;
+            }
+            seen |= 1 << nibble;
+            digit += 1;
+        }
+        half += 1;
+    }
+    (key >> 28) & 0xf != (key >> 32) & 0xf
+}
+
+/// Makes a squares key that follows the rules of the paper, drawing the digits of each half without replacement
+pub fn make_squares_key(mut prng: PRNG<SplitMix64>) -> u64 {
+    let mut key = 0u64;
+    for half in 0..2 {
+        let mut digits: [u64; 15] = core::array::from_fn(|i| i as u64 + 1);
+        let mut remaining = digits.len();
+        for position in 0..8 {
+            let mut pick = prng.gen_u64_in_range(remaining as u64) as usize;
+            // the first digit of the lower half can't repeat the last digit of the upper half
+            if half == 1 && position == 0 {
+                while digits[pick] == key & 0xf {
+                    pick = prng.gen_u64_in_range(remaining as u64) as usize;
+                }
+            }
+            key = (key << 4) | digits[pick];
+            remaining -= 1;
+            digits.swap(pick, remaining);
+        }
+    }
+    key
+}
+
+/// This is the struct definition of the 32 bit Squares generator, the outputs are ``squares32(ctr, key)`` for
+/// successive counters
+pub struct Squares32 {
+    pub(crate) key: u64,
+    pub(crate) ctr: u64,
+}
+
+/// This is the struct definition of the 64 bit Squares generator, the outputs are ``squares64(ctr, key)`` for
+/// successive counters
+pub struct Squares64 {
+    pub(crate) key: u64,
+    pub(crate) ctr: u64,
+}
+
+macro_rules! squares_setup {
+    ($algo_type:ident, $output:ty, $function:ident) => {
+        impl $algo_type {
+            /// Constructs the generator from a key, starting at counter zero. The key is used as given, see
+            /// [`is_valid_squares_key`] for the rules a good key follows
+            pub const fn new(key: u64) -> Self {
+                $algo_type { key, ctr: 0 }
+            }
+
+            /// The key of the stream
+            pub const fn key(&self) -> u64 {
+                self.key
+            }
+
+            /// The counter of the next output
+            pub const fn counter(&self) -> u64 {
+                self.ctr
+            }
+
+            /// Seeks so the next output is the one for `ctr`
+            pub const fn seek(&mut self, ctr: u64) {
+                self.ctr = ctr;
+            }
+        }
+
+        impl Algorithm for $algo_type {
+            type Output = $output;
+
+            #[inline(always)]
+            fn gen(&mut self) -> Self::Output {
+                let value = $function(self.ctr, self.key);
+                self.ctr = self.ctr.wrapping_add(1);
+                value
+            }
+        }
+
+        impl Iterator for $algo_type {
+            type Item = $output;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                Some(self.gen())
+            }
+        }
+
+        impl Default for $algo_type {
+            fn default() -> Self {
+                let prng = PRNG {
+                    generator: make_default_stream(),
+                };
+                Self::new(make_squares_key(prng))
+            }
+        }
+
+        /// Each seed gives a different valid key, so seeds can be used as stream numbers
+        impl<T: AlgorithmOutput> From<T> for $algo_type {
+            fn from(value: T) -> Self {
+                let prng = PRNG {
+                    generator: make_stream::<T>(value),
+                };
+                Self::new(make_squares_key(prng))
+            }
+        }
+    };
+}
+
+squares_setup! {Squares32, u32, squares32}
+squares_setup! {Squares64, u64, squares64}
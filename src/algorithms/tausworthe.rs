@@ -0,0 +1,107 @@
+//! This module implements L'Ecuyer's combined Tausworthe generators ``taus88`` and ``lfsr113``
+//!
+//! Both xor together a few small linear feedback shift registers, so they are fast and have proven
+//! equidistribution, but like every linear generator over GF(2) they fail the linear complexity tests of
+//! ``BigCrush``. They are here for reproducing codes that use them. Each component register needs a seed above a
+//! small minimum, which seeding through `SplitMix64` respects.
+//!
+//! Algorithms from "Maximally Equidistributed Combined Tausworthe Generators" - Pierre L'Ecuyer Mathematics of
+//! Computation 1996 and "Tables of Maximally Equidistributed Combined LFSR Generators" - Pierre L'Ecuyer
+//! Mathematics of Computation 1999
+
+use crate::smol_core::Algorithm;
+use crate::{make_default_stream, make_stream, prng_setup, AlgorithmOutput, SplitMix64, PRNG};
+
+/// Simple struct definition of `Taus88`, a combination of three Tausworthe generators with period about 2^88
+pub struct Taus88 {
+    pub(crate) data: [u32; 3],
+}
+
+/// Simple struct definition of `Lfsr113`, a combination of four Tausworthe generators with period about 2^113
+pub struct Lfsr113 {
+    pub(crate) data: [u32; 4],
+}
+
+/// Makes the words of a combined Tausworthe generator, each drawn uniformly from the seeds above its minimum
+fn make_tausworthe_state<const N: usize>(
+    mut prng: PRNG<SplitMix64>,
+    minimums: [u32; N],
+) -> [u32; N] {
+    minimums
+        .map(|minimum| minimum + prng.gen_u64_in_range(u64::from(u32::MAX - minimum) + 1) as u32)
+}
+
+/// Makes the state of ``Taus88``, the seeds must be above 1, 7 and 15
+fn make_taus88_state(prng: PRNG<SplitMix64>) -> [u32; 3] {
+    make_tausworthe_state(prng, [2, 8, 16])
+}
+
+/// Makes the state of ``Lfsr113``, the seeds must be above 1, 7, 15 and 127
+fn make_lfsr113_state(prng: PRNG<SplitMix64>) -> [u32; 4] {
+    make_tausworthe_state(prng, [2, 8, 16, 128])
+}
+
+prng_setup! {Taus88, Taus88,data, make_taus88_state}
+prng_setup! {Lfsr113, Lfsr113,data, make_lfsr113_state}
+
+/// One step of a Tausworthe component with parameters ``q``, ``s`` and ``k - s`` and the mask of its ``k`` bits
+#[inline(always)]
+const fn tausworthe_step(z: u32, mask: u32, q: u32, s: u32, k_minus_s: u32) -> u32 {
+    let b = ((z << q) ^ z) >> k_minus_s;
+    ((z & mask) << s) ^ b
+}
+
+impl Algorithm for Taus88 {
+    type Output = u32;
+
+    /// Translated from the C source of the 1996 paper, the state is ``[s1, s2, s3]``
+    ///
+    ///```C
+    /// b = (((s1 << 13) ^ s1) >> 19);
+    /// s1 = (((s1 & 4294967294) << 12) ^ b);
+    /// b = (((s2 << 2) ^ s2) >> 25);
+    /// s2 = (((s2 & 4294967288) << 4) ^ b);
+    /// b = (((s3 << 3) ^ s3) >> 11);
+    /// s3 = (((s3 & 4294967280) << 17) ^ b);
+    /// return (s1 ^ s2 ^ s3);
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let [s1, s2, s3] = self.data;
+        self.data = [
+            tausworthe_step(s1, 0xffff_fffe, 13, 12, 19),
+            tausworthe_step(s2, 0xffff_fff8, 2, 4, 25),
+            tausworthe_step(s3, 0xffff_fff0, 3, 17, 11),
+        ];
+        self.data[0] ^ self.data[1] ^ self.data[2]
+    }
+}
+
+impl Algorithm for Lfsr113 {
+    type Output = u32;
+
+    /// Translated from the C source of the 1999 paper, the state is ``[z1, z2, z3, z4]``
+    ///
+    ///```C
+    /// b  = ((z1 << 6) ^ z1) >> 13;
+    /// z1 = ((z1 & 4294967294U) << 18) ^ b;
+    /// b  = ((z2 << 2) ^ z2) >> 27;
+    /// z2 = ((z2 & 4294967288U) << 2) ^ b;
+    /// b  = ((z3 << 13) ^ z3) >> 21;
+    /// z3 = ((z3 & 4294967280U) << 7) ^ b;
+    /// b  = ((z4 << 3) ^ z4) >> 12;
+    /// z4 = ((z4 & 4294967168U) << 13) ^ b;
+    /// return (z1 ^ z2 ^ z3 ^ z4);
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let [z1, z2, z3, z4] = self.data;
+        self.data = [
+            tausworthe_step(z1, 0xffff_fffe, 6, 18, 13),
+            tausworthe_step(z2, 0xffff_fff8, 2, 2, 27),
+            tausworthe_step(z3, 0xffff_fff0, 13, 7, 21),
+            tausworthe_step(z4, 0xffff_ff80, 3, 13, 12),
+        ];
+        self.data[0] ^ self.data[1] ^ self.data[2] ^ self.data[3]
+    }
+}
@@ -0,0 +1,33 @@
+//! This module implements `WyRand`, the generator of Wang Yi's wyhash
+
+use crate::smol_core::Algorithm;
+use crate::{make_1_u64, make_default_stream, make_stream, prng_setup, AlgorithmOutput, PRNG};
+
+/// Simple struct definition of the `WyRand` Algorithm, a Weyl sequence hashed by the wyhash mixer
+///
+/// The mixer folds the 128 bit product of two 64 bit words. One of the fastest generators that passes ``BigCrush`` and `PractRand`, with a period of 2^64
+pub struct WyRand {
+    pub(crate) data: u64,
+}
+
+prng_setup! {WyRand, WyRand,data, make_1_u64}
+
+impl Algorithm for WyRand {
+    type Output = u64;
+
+    /// Translated from the C source of wyhash final 3 that can be found [here](https://github.com/wangyi-fudan/wyhash),
+    /// the constants later versions changed are kept, as other ports such as `fastrand` use them
+    ///
+    ///```C
+    /// static inline uint64_t wyrand(uint64_t *seed){
+    ///     *seed += 0xa0761d6478bd642full;
+    ///     return _wymix(*seed, *seed ^ 0xe7037ed1a0b428dbull);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        self.data = self.data.wrapping_add(0xa076_1d64_78bd_642f);
+        let t = u128::from(self.data) * u128::from(self.data ^ 0xe703_7ed1_a0b4_28db);
+        (t >> 64) as u64 ^ t as u64
+    }
+}
@@ -4,14 +4,14 @@
 
 use crate::smol_core::Algorithm;
 use crate::{
-    make_2_u64, make_4_u32, make_default_stream, make_stream, prng_setup, AlgorithmOutput, PRNG,
+    make_16_u64, make_2_u64, make_default_stream, make_stream, prng_setup, AlgorithmOutput, PRNG,
 };
 
 ///This is the simple struct definition for the `XoroShiro128PlusPlus` algorithm
 pub struct XoroShiro128PlusPlus {
-    pub(crate) data: [u32; 4],
+    pub(crate) data: [u64; 2],
 }
-prng_setup! {XoroShiro128PlusPlus, XoroShiro128PlusPlus,data, make_4_u32}
+prng_setup! {XoroShiro128PlusPlus, XoroShiro128PlusPlus,data, make_2_u64}
 
 ///This is the simple struct definition for the `XoroShiro128SuperStar` algorithm
 pub struct XoroShiro128SuperStar {
@@ -28,37 +28,42 @@ pub struct XoroShiro128Plus {
 prng_setup! {XoroShiro128Plus, XoroShiro128Plus,data, make_2_u64}
 
 impl Algorithm for XoroShiro128PlusPlus {
-    type Output = u32;
+    type Output = u64;
+
+    /// Revision 2 replaces the implementation, which was xoshiro128++ on a ``u32`` state, with xoroshiro128++.
+    /// The previous algorithm is available as `XoShiro128PlusPlus`
+    const REVISION: u32 = 2;
+
     #[inline(always)]
     fn gen(&mut self) -> Self::Output {
-        let result = self.data[0]
-            .overflowing_add(self.data[3])
+        let s0 = self.data[0];
+        let mut s1 = self.data[1];
+        let result = s0
+            .overflowing_add(s1)
             .0
-            .rotate_left(7)
-            .overflowing_add(self.data[0])
+            .rotate_left(17)
+            .overflowing_add(s0)
             .0;
 
-        let t = self.data[1].overflowing_shl(9).0;
-
-        self.data[2] ^= self.data[0];
-        self.data[3] ^= self.data[1];
-        self.data[1] ^= self.data[2];
-        self.data[0] ^= self.data[3];
-
-        self.data[2] ^= t;
-        self.data[3] = self.data[3].rotate_left(11);
+        s1 ^= s0;
+        self.data[0] = s0.rotate_left(49) ^ s1 ^ (s1.overflowing_shl(21).0);
+        self.data[1] = s1.rotate_left(28);
         result
     }
 }
 
 impl Algorithm for XoroShiro128SuperStar {
     type Output = u64;
+
+    /// Revision 2 mixes ``s0`` into ``s1`` in the state update, it was ``s1 ^= 0``
+    const REVISION: u32 = 2;
+
     #[inline(always)]
     fn gen(&mut self) -> Self::Output {
         let s0 = self.data[0];
         let mut s1 = self.data[1];
         let result = s0.overflowing_mul(5).0.rotate_left(7).overflowing_mul(9).0;
-        s1 ^= 0;
+        s1 ^= s0;
         self.data[0] = s0.rotate_left(24) ^ s1 ^ (s1.overflowing_shl(16).0);
         self.data[1] = s1.rotate_left(37);
         result
@@ -79,3 +84,148 @@ impl Algorithm for XoroShiro128Plus {
         result
     }
 }
+
+const XOROSHIRO_1024_JUMP: [u64; 16] = [
+    0x9311_97d8_e317_7f17,
+    0xb594_22e0_b913_8c5f,
+    0xf06a_6afb_49d6_68bb,
+    0xacb8_a641_2c8a_1401,
+    0x1230_4ec8_5f0b_3468,
+    0xb7df_e707_9209_891e,
+    0x405b_7eec_77d9_eb14,
+    0x34ea_d682_80c4_4e4a,
+    0xe0e4_ba3e_0ac9_e366,
+    0x8f46_eda8_3489_05b7,
+    0x328b_f4db_ad90_d6ff,
+    0xc8fd_6fb3_1c9e_ffc3,
+    0xe899_d452_d4b6_7652,
+    0x45f3_8728_6ade_3205,
+    0x0386_4f45_4a89_20bd,
+    0xa68f_a287_25b1_b384,
+];
+
+const XOROSHIRO_1024_LONG_JUMP: [u64; 16] = [
+    0x7374_1563_60bb_f00f,
+    0x4630_c2ef_a3b3_c1f6,
+    0x6654_183a_8927_86b1,
+    0x94f7_bfcb_fb0f_1661,
+    0x27d8_243d_3d13_eb2d,
+    0x9701_730f_3dfb_300f,
+    0x2f29_3baa_e6f6_04ad,
+    0xa661_831c_b60c_d8b6,
+    0x6828_0c77_d9fe_008c,
+    0x5055_4160_f5ba_9459,
+    0x2fc2_0b17_ec7b_2a9a,
+    0x4918_9bbd_c8ec_9f8f,
+    0x92a6_5bca_4185_2cc1,
+    0xf468_20dd_0509_c12a,
+    0x52b0_0c35_fbf9_2185,
+    0x1e5b_3b7f_589e_03c1,
+];
+
+/// The xoroshiro1024 generators keep their 16 words of state in a circular buffer, so they have an index next to the
+/// ``data`` field that `prng_setup!` can not fill, and the impls are written out here
+macro_rules! xoroshiro_1024 {
+    ($(#[$attr:meta])* $algo_name:ident, |$s0:ident, $s15:ident| $scrambler:expr) => {
+        $(#[$attr])*
+        pub struct $algo_name {
+            pub(crate) data: [u64; 16],
+            pub(crate) index: usize,
+        }
+
+        impl $algo_name {
+            /// Jumps ahead by 2^512 outputs, to give 2^512 non overlapping streams
+            pub fn jump(&mut self) {
+                self.apply_jump(&XOROSHIRO_1024_JUMP);
+            }
+
+            /// Jumps ahead by 2^768 outputs, to give 2^256 starting points with 2^512 streams each
+            pub fn long_jump(&mut self) {
+                self.apply_jump(&XOROSHIRO_1024_LONG_JUMP);
+            }
+
+            fn apply_jump(&mut self, polynomial: &[u64; 16]) {
+                let mut state = [0u64; 16];
+                for word in polynomial {
+                    for bit in 0..64 {
+                        if word >> bit & 1 == 1 {
+                            for (i, target) in state.iter_mut().enumerate() {
+                                *target ^= self.data[(i + self.index) & 15];
+                            }
+                        }
+                        self.gen();
+                    }
+                }
+                for (i, value) in state.into_iter().enumerate() {
+                    self.data[(i + self.index) & 15] = value;
+                }
+            }
+        }
+
+        impl Algorithm for $algo_name {
+            type Output = u64;
+
+            #[inline(always)]
+            fn gen(&mut self) -> Self::Output {
+                let q = self.index;
+                self.index = (self.index + 1) & 15;
+                let $s0 = self.data[self.index];
+                let $s15 = self.data[q];
+                let result = $scrambler;
+
+                let s15 = $s15 ^ $s0;
+                self.data[q] = $s0.rotate_left(25) ^ s15 ^ (s15 << 27);
+                self.data[self.index] = s15.rotate_left(36);
+                result
+            }
+        }
+
+        impl Iterator for $algo_name {
+            type Item = u64;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                Some(self.gen())
+            }
+        }
+
+        impl Default for $algo_name {
+            fn default() -> Self {
+                let prng = PRNG {
+                    generator: make_default_stream(),
+                };
+                $algo_name {
+                    data: make_16_u64(prng),
+                    index: 0,
+                }
+            }
+        }
+
+        impl<T: AlgorithmOutput> From<T> for $algo_name {
+            fn from(value: T) -> Self {
+                let prng = PRNG {
+                    generator: make_stream::<T>(value),
+                };
+                $algo_name {
+                    data: make_16_u64(prng),
+                    index: 0,
+                }
+            }
+        }
+    };
+}
+
+xoroshiro_1024! {
+    ///This is the simple struct definition for the `XoroShiro1024SuperStar` algorithm, for massively parallel use
+    XoroShiro1024SuperStar, |s0, s15| s0.wrapping_mul(5).rotate_left(7).wrapping_mul(9)
+}
+
+xoroshiro_1024! {
+    ///This is the simple struct definition for the `XoroShiro1024PlusPlus` algorithm, for massively parallel use
+    XoroShiro1024PlusPlus, |s0, s15| s0.wrapping_add(s15).rotate_left(23).wrapping_add(s15)
+}
+
+xoroshiro_1024! {
+    ///This is the simple struct definition for the `XoroShiro1024Star` algorithm, the low bits are weak so it is
+    /// meant for generating floating point numbers
+    XoroShiro1024Star, |s0, s15| s0.wrapping_mul(0x9e37_79b9_7f4a_7c13)
+}
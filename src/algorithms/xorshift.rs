@@ -5,7 +5,7 @@
 use crate::smol_core::Algorithm;
 use crate::{
     make_1_u32, make_1_u64, make_2_u64, make_4_u32, make_default_stream, make_stream, prng_setup,
-    AlgorithmOutput, PRNG,
+    AlgorithmOutput, SplitMix64, PRNG,
 };
 
 /// This is the struct definition for the `XorShift32` algorithm
@@ -35,6 +35,46 @@ pub struct XorShift128Plus {
 
 prng_setup! {XorShift128Plus, XorShift128Plus,data, make_2_u64}
 
+/// This is the struct definition for the `Xorwow` algorithm, Marsaglia's xorshift with a Weyl sequence added to the
+/// output and the default generator of ``cuRAND``
+///
+/// The state is ``[x, y, z, w, v, d]`` and the period is 2^192 - 2^32
+pub struct Xorwow {
+    pub(crate) data: [u32; 6],
+}
+
+/// Makes the state of ``Xorwow``, the five xorshift words must not all be zero
+fn make_xorwow_state(mut prng: PRNG<SplitMix64>) -> [u32; 6] {
+    let mut state = [0; 6];
+    while state[..5] == [0; 5] {
+        state = [(); 6].map(|()| prng.gen_u32());
+    }
+    state
+}
+
+prng_setup! {Xorwow, Xorwow,data, make_xorwow_state}
+
+impl Xorwow {
+    /// Makes the generator in the state that ``curand_init(seed, 0, 0, &state)`` gives the ``cuRAND`` default
+    /// generator, so the outputs match ``curand(&state)`` for subsequence and offset zero
+    #[must_use]
+    pub const fn from_curand_seed(seed: u64) -> Self {
+        let t0 = 1_099_087_573u32.wrapping_mul(seed as u32 ^ 0xaad2_6b49);
+        let t1 = 2_591_861_531u32.wrapping_mul((seed >> 32) as u32 ^ 0xf7dc_efdd);
+        // the xored constants are 362436069 and 88675123 as in the cuRAND source
+        Self {
+            data: [
+                123_456_789u32.wrapping_add(t0),
+                0x159a_55e5 ^ t0,
+                521_288_629u32.wrapping_add(t1),
+                0x0549_1333 ^ t1,
+                5_783_321u32.wrapping_add(t0),
+                6_615_241u32.wrapping_add(t1).wrapping_add(t0),
+            ],
+        }
+    }
+}
+
 impl Algorithm for XorShift32 {
     type Output = u32;
     #[inline(always)]
@@ -97,3 +137,22 @@ impl Algorithm for XorShift128Plus {
         s1.overflowing_add(s0).0
     }
 }
+
+impl Algorithm for Xorwow {
+    type Output = u32;
+
+    /// Translated from the C source of "Xorshift RNGs" - George Marsaglia Journal of Statistical Software 2003
+    ///
+    ///```C
+    /// unsigned long t=(x^(x>>2)); x=y; y=z; z=w; w=v; v=(v^(v<<4))^(t^(t<<1)); return (d+=362437)+v;
+    /// ```
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let [x, .., v, d] = self.data;
+        let t = x ^ (x >> 2);
+        let next = (v ^ (v << 4)) ^ (t ^ (t << 1));
+        let d = d.wrapping_add(362_437);
+        self.data = [self.data[1], self.data[2], self.data[3], v, next, d];
+        d.wrapping_add(next)
+    }
+}
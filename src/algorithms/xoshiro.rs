@@ -2,7 +2,72 @@
 //! Te Implmentations are primarilly based on translations of the C code from [Prof. Vigna](https://prng.di.unimi.it/).
 
 use crate::smol_core::Algorithm;
-use crate::{make_4_u64, make_default_stream, make_stream, prng_setup, AlgorithmOutput, PRNG};
+use crate::{
+    make_4_u32, make_4_u64, make_8_u64, make_default_stream, make_stream, prng_setup,
+    AlgorithmOutput, PRNG,
+};
+
+/// Implements ``jump`` and ``long_jump`` from the jump polynomials of the reference, the words of each constant hold
+/// the coefficients of ``x^steps`` modulo the characteristic polynomial of the generator
+macro_rules! xoshiro_jumps {
+    ($algo_type:ty, $word:ty, $jump:ident, $jump_log:literal, $long_jump:ident, $long_jump_log:literal) => {
+        impl $algo_type {
+            #[doc = concat!(
+                "Jumps ahead by 2^", $jump_log, " outputs, to start non overlapping streams for parallel use"
+            )]
+            pub fn jump(&mut self) {
+                self.apply_jump(&$jump);
+            }
+
+            #[doc = concat!(
+                "Jumps ahead by 2^", $long_jump_log,
+                " outputs, to give starting points for sets of streams made with ``jump``"
+            )]
+            pub fn long_jump(&mut self) {
+                self.apply_jump(&$long_jump);
+            }
+
+            fn apply_jump(&mut self, polynomial: &[$word]) {
+                let mut state = self.data.map(|_| 0);
+                for word in polynomial {
+                    for bit in 0..<$word>::BITS {
+                        if word >> bit & 1 == 1 {
+                            for (target, value) in state.iter_mut().zip(self.data) {
+                                *target ^= value;
+                            }
+                        }
+                        self.gen();
+                    }
+                }
+                self.data = state;
+            }
+        }
+    };
+}
+
+const XOSHIRO_128_JUMP: [u32; 4] = [0x8764_000b, 0xf542_d2d3, 0x6fa0_35c3, 0x77f2_db5b];
+const XOSHIRO_128_LONG_JUMP: [u32; 4] = [0xb523_952e, 0x0b6f_099f, 0xccf5_a0ef, 0x1c58_0662];
+
+const XOSHIRO_512_JUMP: [u64; 8] = [
+    0x33ed_89b6_e7a3_53f9,
+    0x7600_83d7_9553_23be,
+    0x2837_f2fb_b5f2_2fae,
+    0x4b8c_5674_d309_511c,
+    0xb11a_c47a_7ba2_8c25,
+    0xf1be_7667_092b_cc1c,
+    0x5385_1efd_b6df_0aaf,
+    0x1ebb_c8b2_3eaf_25db,
+];
+const XOSHIRO_512_LONG_JUMP: [u64; 8] = [
+    0x1146_7fef_8f92_1d28,
+    0xa2a8_19f2_e79c_8ea8,
+    0xa829_9fc2_84b3_959a,
+    0xb4d3_4734_0ca6_3ee1,
+    0x1cb0_940b_edbf_f6ce,
+    0xd956_c5c4_fa1f_8e17,
+    0x915e_38fd_4eda_93bc,
+    0x5b3c_cdfa_5d7d_aca5,
+];
 
 /// This is the struct definition for the state of the `XoShiro256SuperStar` algorithm
 pub struct XoShiro256SuperStar {
@@ -24,14 +89,62 @@ pub struct XoShiro256Plus {
 
 prng_setup! {XoShiro256Plus, XoShiro256Plus,data, make_4_u64}
 
+/// This is the struct definition of the state of `XoShiro128PlusPlus` algorithm, the 32 bit member of the family
+pub struct XoShiro128PlusPlus {
+    pub(crate) data: [u32; 4],
+}
+
+prng_setup! {XoShiro128PlusPlus, XoShiro128PlusPlus,data, make_4_u32}
+
+/// This is the struct definition of the state of `XoShiro128SuperStar` algorithm, the 32 bit all purpose generator
+pub struct XoShiro128SuperStar {
+    pub(crate) data: [u32; 4],
+}
+
+prng_setup! {XoShiro128SuperStar, XoShiro128SuperStar,data, make_4_u32}
+
+/// This is the struct definition of the state of `XoShiro128Plus` algorithm, the low bits are weak so it is meant
+/// for generating ``f32``
+pub struct XoShiro128Plus {
+    pub(crate) data: [u32; 4],
+}
+
+prng_setup! {XoShiro128Plus, XoShiro128Plus,data, make_4_u32}
+
+/// This is the struct definition of the state of `XoShiro512SuperStar` algorithm, for when 2^256 non overlapping
+/// streams are not enough
+pub struct XoShiro512SuperStar {
+    pub(crate) data: [u64; 8],
+}
+
+prng_setup! {XoShiro512SuperStar, XoShiro512SuperStar,data, make_8_u64}
+
+/// This is the struct definition of the state of `XoShiro512PlusPlus` algorithm, for when 2^256 non overlapping
+/// streams are not enough
+pub struct XoShiro512PlusPlus {
+    pub(crate) data: [u64; 8],
+}
+
+prng_setup! {XoShiro512PlusPlus, XoShiro512PlusPlus,data, make_8_u64}
+
+xoshiro_jumps! {XoShiro128Plus, u32, XOSHIRO_128_JUMP, 64, XOSHIRO_128_LONG_JUMP, 96}
+xoshiro_jumps! {XoShiro128PlusPlus, u32, XOSHIRO_128_JUMP, 64, XOSHIRO_128_LONG_JUMP, 96}
+xoshiro_jumps! {XoShiro128SuperStar, u32, XOSHIRO_128_JUMP, 64, XOSHIRO_128_LONG_JUMP, 96}
+xoshiro_jumps! {XoShiro512PlusPlus, u64, XOSHIRO_512_JUMP, 256, XOSHIRO_512_LONG_JUMP, 384}
+xoshiro_jumps! {XoShiro512SuperStar, u64, XOSHIRO_512_JUMP, 256, XOSHIRO_512_LONG_JUMP, 384}
+
 impl Algorithm for XoShiro256SuperStar {
     type Output = u64;
+
+    /// Revision 2 corrects the rotations, which were to the right instead of to the left
+    const REVISION: u32 = 2;
+
     #[inline(always)]
     fn gen(&mut self) -> Self::Output {
         let result = self.data[1]
             .overflowing_mul(5)
             .0
-            .rotate_right(7)
+            .rotate_left(7)
             .overflowing_mul(9)
             .0;
         let t = self.data[1].overflowing_shl(17).0;
@@ -42,7 +155,7 @@ impl Algorithm for XoShiro256SuperStar {
         self.data[0] ^= self.data[3];
 
         self.data[2] ^= t;
-        self.data[3] = self.data[3].rotate_right(45);
+        self.data[3] = self.data[3].rotate_left(45);
         result
     }
 }
@@ -67,9 +180,13 @@ impl Algorithm for XoShiro256Plus {
 
 impl Algorithm for XoShiro256PlusPlus {
     type Output = u64;
+
+    /// Revision 2 corrects the scrambler to rotate ``s[0] + s[3]``, it was ``s[0] + s[0]``
+    const REVISION: u32 = 2;
+
     #[inline(always)]
     fn gen(&mut self) -> Self::Output {
-        let result = (self.data[0].overflowing_add(self.data[0]).0)
+        let result = (self.data[0].overflowing_add(self.data[3]).0)
             .rotate_left(23)
             .overflowing_add(self.data[0])
             .0;
@@ -85,3 +202,106 @@ impl Algorithm for XoShiro256PlusPlus {
         result
     }
 }
+
+impl Algorithm for XoShiro128PlusPlus {
+    type Output = u32;
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let result = self.data[0]
+            .overflowing_add(self.data[3])
+            .0
+            .rotate_left(7)
+            .overflowing_add(self.data[0])
+            .0;
+
+        let t = self.data[1].overflowing_shl(9).0;
+
+        self.data[2] ^= self.data[0];
+        self.data[3] ^= self.data[1];
+        self.data[1] ^= self.data[2];
+        self.data[0] ^= self.data[3];
+
+        self.data[2] ^= t;
+        self.data[3] = self.data[3].rotate_left(11);
+        result
+    }
+}
+
+impl Algorithm for XoShiro128SuperStar {
+    type Output = u32;
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let result = self.data[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+
+        let t = self.data[1] << 9;
+
+        self.data[2] ^= self.data[0];
+        self.data[3] ^= self.data[1];
+        self.data[1] ^= self.data[2];
+        self.data[0] ^= self.data[3];
+
+        self.data[2] ^= t;
+        self.data[3] = self.data[3].rotate_left(11);
+        result
+    }
+}
+
+impl Algorithm for XoShiro128Plus {
+    type Output = u32;
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let result = self.data[0].wrapping_add(self.data[3]);
+
+        let t = self.data[1] << 9;
+
+        self.data[2] ^= self.data[0];
+        self.data[3] ^= self.data[1];
+        self.data[1] ^= self.data[2];
+        self.data[0] ^= self.data[3];
+
+        self.data[2] ^= t;
+        self.data[3] = self.data[3].rotate_left(11);
+        result
+    }
+}
+
+/// The linear engine shared by the xoshiro512 generators
+#[inline(always)]
+const fn xoshiro_512_step(s: &mut [u64; 8]) {
+    let t = s[1] << 11;
+
+    s[2] ^= s[0];
+    s[5] ^= s[1];
+    s[1] ^= s[2];
+    s[7] ^= s[3];
+    s[3] ^= s[4];
+    s[4] ^= s[5];
+    s[0] ^= s[6];
+    s[6] ^= s[7];
+
+    s[6] ^= t;
+    s[7] = s[7].rotate_left(21);
+}
+
+impl Algorithm for XoShiro512SuperStar {
+    type Output = u64;
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let result = self.data[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        xoshiro_512_step(&mut self.data);
+        result
+    }
+}
+
+impl Algorithm for XoShiro512PlusPlus {
+    type Output = u64;
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        let result = self.data[0]
+            .wrapping_add(self.data[2])
+            .rotate_left(17)
+            .wrapping_add(self.data[2]);
+        xoshiro_512_step(&mut self.data);
+        result
+    }
+}
@@ -0,0 +1,161 @@
+//! Streams the raw output of a generator to stdout, for use with external test suites such as `PractRand`,
+//! `TestU01` and `dieharder`
+//!
+//! ```text
+//! smolprng-dump <algorithm> [--seed N] [--bytes N] [--stdin32] [--reverse]
+//! smolprng-dump --list
+//! ```
+//!
+//! Outputs are written as little-endian words of the native width of the algorithm. With `--stdin32` every word
+//! is 32 bits, taken from the high bits of wider outputs or concatenated from narrower ones. `--reverse` reverses
+//! the bits of every native output first, so that the low bits are the ones that the test suites look at closely.
+
+use smolprng::{registry, DynAlgorithm};
+use std::io::{self, Write};
+use std::process::ExitCode;
+
+const BUFFER_SIZE: usize = 1 << 16;
+
+const USAGE: &str =
+    "usage: smolprng-dump <algorithm> [--seed N] [--bytes N] [--stdin32] [--reverse]
+       smolprng-dump --list
+
+  --seed N     seed the generator from N, decimal or 0x prefixed hex, instead of the default state
+  --bytes N    stop after N bytes, 0 or absent streams forever
+  --stdin32    write 32 bit words regardless of the output width of the algorithm
+  --reverse    reverse the bits of every output of the algorithm";
+
+struct Options {
+    seed: Option<u64>,
+    bytes: Option<u64>,
+    stdin32: bool,
+    reverse: bool,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.iter().any(|arg| arg == "--list") {
+        let mut out = io::stdout().lock();
+        for info in registry::list_algorithms() {
+            if writeln!(out, "{}", info.name).is_err() {
+                break;
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let (name, options) = match parse_args(&args) {
+        Ok(parsed) => parsed,
+        Err(message) => {
+            eprintln!("smolprng-dump: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let Some(info) = registry::find(&name) else {
+        eprintln!("smolprng-dump: unknown algorithm '{name}', see --list");
+        return ExitCode::from(2);
+    };
+
+    match dump(info.construct(options.seed), &options) {
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => {
+            eprintln!("smolprng-dump: {err}");
+            ExitCode::FAILURE
+        }
+        // the reader closing the pipe is the normal way for an infinite stream to end
+        _ => ExitCode::SUCCESS,
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<(String, Options), String> {
+    let mut name = None;
+    let mut options = Options {
+        seed: None,
+        bytes: None,
+        stdin32: false,
+        reverse: false,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => options.seed = Some(parse_u64(args.next(), "--seed")?),
+            "--bytes" => {
+                options.bytes = Some(parse_u64(args.next(), "--bytes")?).filter(|&bytes| bytes > 0);
+            }
+            "--stdin32" => options.stdin32 = true,
+            "--reverse" => options.reverse = true,
+            "-h" | "--help" => return Err("help requested".into()),
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{flag}'")),
+            algorithm if name.is_none() => name = Some(algorithm.to_string()),
+            extra => return Err(format!("unexpected argument '{extra}'")),
+        }
+    }
+
+    name.map(|name| (name, options))
+        .ok_or_else(|| "missing algorithm name".into())
+}
+
+fn parse_u64(value: Option<&String>, flag: &str) -> Result<u64, String> {
+    let value = value.ok_or_else(|| format!("{flag} needs a value"))?;
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    parsed.map_err(|_| format!("invalid value '{value}' for {flag}"))
+}
+
+fn dump(mut generator: Box<dyn DynAlgorithm>, options: &Options) -> io::Result<()> {
+    let generator = generator.as_mut();
+    let mut buffer = vec![0u8; BUFFER_SIZE];
+    let mut remaining = options.bytes;
+    let mut out = io::stdout().lock();
+
+    loop {
+        match (options.stdin32, options.reverse) {
+            (false, false) => generator.fill_bytes(&mut buffer),
+            (true, false) => {
+                for chunk in buffer.chunks_exact_mut(4) {
+                    chunk.copy_from_slice(&generator.next_u32().to_le_bytes());
+                }
+            }
+            (stdin32, true) => fill_reversed(generator, &mut buffer, stdin32),
+        }
+
+        match remaining {
+            None => out.write_all(&buffer)?,
+            Some(bytes) if bytes <= BUFFER_SIZE as u64 => {
+                out.write_all(&buffer[..bytes as usize])?;
+                return out.flush();
+            }
+            Some(bytes) => {
+                out.write_all(&buffer)?;
+                remaining = Some(bytes - BUFFER_SIZE as u64);
+            }
+        }
+    }
+}
+
+/// Fills the buffer with bit reversed outputs, as native words or as 32 bit words
+fn fill_reversed(generator: &mut dyn DynAlgorithm, buffer: &mut [u8], stdin32: bool) {
+    let bits = generator.output_bits();
+    let mut next = || generator.next_output().reverse_bits() >> (128 - bits);
+
+    if !stdin32 {
+        let size = bits as usize / 8;
+        for chunk in buffer.chunks_exact_mut(size) {
+            chunk.copy_from_slice(&next().to_le_bytes()[..size]);
+        }
+        return;
+    }
+
+    for chunk in buffer.chunks_exact_mut(4) {
+        let word = if bits >= 32 {
+            (next() >> (bits - 32)) as u32
+        } else {
+            (0..32 / bits).fold(0u32, |word, _| (word << bits) | next() as u32)
+        };
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+}
@@ -0,0 +1,736 @@
+//! Samples a distribution from the command line and writes the samples as CSV or as raw little-endian ``f64``
+//!
+//! ```text
+//! smolprng-sample --dist gamma --params 2.0,1.0 --n 1e6 [--seed 42] [--algo jsf64] [--format csv|f64le] [--output FILE]
+//! smolprng-sample --list
+//! ```
+//!
+//! The same arguments always produce the same samples. Integer valued distributions are written as integers in CSV
+//! and as exactly converted ``f64`` in the binary format.
+//!
+//! Vector valued distributions, such as ``dirichlet``, the geometric samplers and the random rotations, write one
+//! row of values per sample, comma separated in CSV and consecutive in the binary format. The last parameter of
+//! ``dirichlet``, ``multinomial``, ``multivariate_normal``, ``triangle`` and ``polygon`` takes any number of values,
+//! for example ``--dist multivariate_normal --params 0,0,1,0.5,0.5,1`` is a 2D mean followed by its covariance.
+//! The sequence helpers, such as ``shuffle`` and ``choose``, act on the caller's data and are not exposed.
+//!
+//! The samplers of ``binomial``, ``multinomial``, ``poisson`` and ``negative_binomial`` take time proportional to
+//! the number of trials or the mean, so these are capped at a million, and ``--list`` shows the bounds.
+
+use smolprng::{
+    registry, Annulus, Dirichlet, DynAlgorithm, Multinomial, MultivariateNormal, Polygon, Triangle,
+    UnitBall, UnitCircle, UnitSimplex, UnitSphere, PRNG,
+};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::process::ExitCode;
+
+type Prng = PRNG<Box<dyn DynAlgorithm>>;
+
+/// Samples one row of a vector valued distribution into the slice
+type SampleRow = Box<dyn FnMut(&mut Prng, &mut [f64])>;
+
+const USAGE: &str = "usage: smolprng-sample --dist NAME [--params A,B] --n N [--seed N] [--algo NAME] [--format csv|f64le] [--output FILE]
+       smolprng-sample --list
+
+  --dist NAME      the distribution to sample, see --list
+  --params A,B     the comma separated parameters of the distribution
+  --n N            the number of samples, such as 1000 or 1e6
+  --seed N         seed the generator from N, decimal or 0x prefixed hex, instead of the default state
+  --algo NAME      the algorithm to generate with, one of the smolprng-dump --list names, defaults to jsf64
+  --format FORMAT  csv for one sample per line after a header, f64le for raw little-endian f64, defaults to csv
+  --output FILE    write to FILE instead of stdout";
+
+/// The largest sample count, and the largest integer that is exact as an ``f64``
+const MAX_COUNT: f64 = 9_007_199_254_740_992f64;
+
+/// The largest number of values in a vector valued sample
+const MAX_DIM: usize = 1 << 16;
+
+/// The largest number of trials of ``binomial`` and ``multinomial`` and the largest mean of ``poisson``, their
+/// samplers take time proportional to it
+const MAX_TRIALS: f64 = 1_000_000f64;
+
+/// The largest ``r`` of ``negative_binomial``, with ``MAX_NB_P`` its poisson mean ``r p / (1 - p)`` stays below
+/// ``MAX_TRIALS``
+const MAX_NB_R: f64 = 1000f64;
+
+/// The largest ``p`` of ``negative_binomial``
+const MAX_NB_P: f64 = 0.999;
+
+/// The support of a parameter
+#[derive(Clone, Copy)]
+enum Support {
+    Real,
+    Positive,
+    NonNegative,
+    Probability,
+    Count,
+    /// Positive and at most the bound
+    PositiveAtMost(f64),
+    /// In ``[0, bound]``
+    NonNegativeAtMost(f64),
+    /// An integer in ``[0, bound]``
+    CountAtMost(f64),
+}
+
+impl Support {
+    fn contains(self, value: f64) -> bool {
+        value.is_finite()
+            && match self {
+                Support::Real => true,
+                Support::Positive => value > 0f64,
+                Support::NonNegative => value >= 0f64,
+                Support::Probability => (0f64..=1f64).contains(&value),
+                Support::Count => Support::CountAtMost(MAX_COUNT).contains(value),
+                Support::PositiveAtMost(bound) => value > 0f64 && value <= bound,
+                Support::NonNegativeAtMost(bound) => (0f64..=bound).contains(&value),
+                Support::CountAtMost(bound) => {
+                    (0f64..=bound).contains(&value) && value.fract() == 0f64
+                }
+            }
+    }
+
+    fn describe(self) -> String {
+        match self {
+            Support::Real => "a finite number".into(),
+            Support::Positive => "positive".into(),
+            Support::NonNegative => "non-negative".into(),
+            Support::Probability => "in [0, 1]".into(),
+            Support::Count => "a non-negative integer".into(),
+            Support::PositiveAtMost(bound) => format!("in (0, {bound}]"),
+            Support::NonNegativeAtMost(bound) => format!("in [0, {bound}]"),
+            Support::CountAtMost(bound) => format!("an integer in [0, {bound}]"),
+        }
+    }
+}
+
+enum Sample {
+    Real(f64),
+    Count(u64),
+}
+
+struct Distribution {
+    name: &'static str,
+    params: &'static [(&'static str, Support)],
+    sample: fn(&mut Prng, &[f64]) -> Sample,
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const DISTRIBUTIONS: &[Distribution] = &[
+    Distribution {
+        name: "uniform",
+        params: &[],
+        sample: |prng, _| Sample::Real(prng.gen_f64()),
+    },
+    Distribution {
+        name: "normal",
+        params: &[],
+        sample: |prng, _| Sample::Real(prng.normal()),
+    },
+    Distribution {
+        name: "lognormal",
+        params: &[],
+        sample: |prng, _| Sample::Real(prng.lognormal()),
+    },
+    Distribution {
+        name: "cauchy",
+        params: &[],
+        sample: |prng, _| Sample::Real(prng.cauchy()),
+    },
+    Distribution {
+        name: "student_t",
+        params: &[("nu", Support::Positive)],
+        sample: |prng, p| Sample::Real(prng.student_t(p[0])),
+    },
+    Distribution {
+        name: "gamma",
+        params: &[("alpha", Support::Positive), ("beta", Support::Positive)],
+        sample: |prng, p| Sample::Real(prng.gamma(p[0], p[1])),
+    },
+    Distribution {
+        name: "chi_squared",
+        params: &[("nu", Support::Positive)],
+        sample: |prng, p| Sample::Real(prng.chi_squared(p[0])),
+    },
+    Distribution {
+        name: "beta",
+        params: &[("alpha", Support::Positive), ("beta", Support::Positive)],
+        sample: |prng, p| Sample::Real(prng.beta(p[0], p[1])),
+    },
+    Distribution {
+        name: "exponential",
+        params: &[("lambda", Support::Positive)],
+        sample: |prng, p| Sample::Real(prng.exponential(p[0])),
+    },
+    Distribution {
+        name: "logistic",
+        params: &[("mu", Support::Real), ("beta", Support::Positive)],
+        sample: |prng, p| Sample::Real(prng.logistic(p[0], p[1])),
+    },
+    Distribution {
+        name: "fischer",
+        params: &[("d1", Support::Positive), ("d2", Support::Positive)],
+        sample: |prng, p| Sample::Real(prng.fischer(p[0], p[1])),
+    },
+    Distribution {
+        name: "bernoulli",
+        params: &[("p", Support::Probability)],
+        sample: |prng, p| Sample::Count(prng.bernoulli(p[0])),
+    },
+    Distribution {
+        name: "binomial",
+        params: &[
+            ("n", Support::CountAtMost(MAX_TRIALS)),
+            ("p", Support::Probability),
+        ],
+        sample: |prng, p| Sample::Count(prng.binomial(p[0] as u64, p[1])),
+    },
+    Distribution {
+        name: "poisson",
+        params: &[("lambda", Support::NonNegativeAtMost(MAX_TRIALS))],
+        sample: |prng, p| Sample::Count(prng.poisson(p[0])),
+    },
+    Distribution {
+        name: "negative_binomial",
+        params: &[
+            ("r", Support::PositiveAtMost(MAX_NB_R)),
+            ("p", Support::PositiveAtMost(MAX_NB_P)),
+        ],
+        sample: |prng, p| Sample::Count(prng.negative_binomial(p[0], p[1])),
+    },
+];
+
+/// A vector valued distribution with validated parameters, ready to sample rows of ``dim`` values
+struct VectorSampler {
+    dim: usize,
+    sample: SampleRow,
+}
+
+/// A distribution with a row of values per sample, its sampler is built once from the parameters
+struct VectorDistribution {
+    name: &'static str,
+    params: &'static [(&'static str, Support)],
+    /// The last parameter takes one or more values
+    repeated: bool,
+    build: fn(&[f64]) -> Result<VectorSampler, String>,
+}
+
+impl VectorSampler {
+    fn new(
+        dim: usize,
+        sample: impl FnMut(&mut Prng, &mut [f64]) + 'static,
+    ) -> Result<Self, String> {
+        Ok(VectorSampler {
+            dim,
+            sample: Box::new(sample),
+        })
+    }
+}
+
+/// Converts a parameter that has been checked to be a count into a dimension of at most `max`
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn dimension(value: f64, max: usize) -> Result<usize, String> {
+    match value as usize {
+        dim @ 1.. if dim <= max => Ok(dim),
+        _ => Err(format!(
+            "the dimension must be between 1 and {max}, got {value}"
+        )),
+    }
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+const VECTOR_DISTRIBUTIONS: &[VectorDistribution] = &[
+    VectorDistribution {
+        name: "dirichlet",
+        params: &[("alpha", Support::Positive)],
+        repeated: true,
+        build: |p| {
+            if p.len() < 2 {
+                return Err("dirichlet takes at least 2 alphas".into());
+            }
+            let dirichlet = Dirichlet::new(p).map_err(|err| err.to_string())?;
+            VectorSampler::new(p.len(), move |prng, out| dirichlet.sample(prng, out))
+        },
+    },
+    VectorDistribution {
+        name: "multinomial",
+        params: &[
+            ("n", Support::CountAtMost(MAX_TRIALS)),
+            ("p", Support::NonNegative),
+        ],
+        repeated: true,
+        build: |p| {
+            let (n, probs) = (p[0] as u64, p[1..].to_vec());
+            if probs.iter().all(|p| *p == 0f64) {
+                return Err("the probabilities of multinomial must not all be zero".into());
+            }
+            // each category but the last is a binomial of up to n trials
+            if p[0] * (probs.len() - 1) as f64 > MAX_TRIALS {
+                return Err(format!(
+                    "multinomial runs up to n trials for each category but the last, n times their number must be at most {MAX_TRIALS}"
+                ));
+            }
+            let mut counts = vec![0u64; probs.len()];
+            VectorSampler::new(probs.len(), move |prng, out| {
+                Multinomial::sample(prng, n, &probs, &mut counts);
+                for (x, count) in out.iter_mut().zip(&counts) {
+                    *x = *count as f64;
+                }
+            })
+        },
+    },
+    VectorDistribution {
+        name: "multivariate_normal",
+        params: &[("mean then row major covariance", Support::Real)],
+        repeated: true,
+        build: |p| {
+            let dim = (1..=p.len())
+                .find(|d| d * d + d >= p.len())
+                .filter(|d| d * d + d == p.len())
+                .ok_or("multivariate_normal takes a mean of length d and a d by d covariance")?;
+            let mvn = MultivariateNormal::new(&p[..dim], &p[dim..], dim)
+                .map_err(|err| err.to_string())?;
+            VectorSampler::new(dim, move |prng, out| mvn.sample(prng, out))
+        },
+    },
+    VectorDistribution {
+        name: "unit_circle",
+        params: &[],
+        repeated: false,
+        build: |_| VectorSampler::new(2, |prng, out| out.copy_from_slice(&UnitCircle.sample(prng))),
+    },
+    VectorDistribution {
+        name: "disc2d",
+        params: &[],
+        repeated: false,
+        build: |_| {
+            VectorSampler::new(2, |prng, out| {
+                let (x, y) = prng.disc2d();
+                out.copy_from_slice(&[x, y]);
+            })
+        },
+    },
+    VectorDistribution {
+        name: "unit_sphere",
+        params: &[("dim", Support::Count)],
+        repeated: false,
+        build: |p| {
+            VectorSampler::new(dimension(p[0], MAX_DIM)?, |prng, out| {
+                UnitSphere.sample(prng, out)
+            })
+        },
+    },
+    VectorDistribution {
+        name: "unit_ball",
+        params: &[("dim", Support::Count)],
+        repeated: false,
+        build: |p| {
+            VectorSampler::new(dimension(p[0], MAX_DIM)?, |prng, out| {
+                UnitBall.sample(prng, out)
+            })
+        },
+    },
+    VectorDistribution {
+        name: "unit_simplex",
+        params: &[("dim", Support::Count)],
+        repeated: false,
+        build: |p| {
+            VectorSampler::new(dimension(p[0], MAX_DIM)?, |prng, out| {
+                UnitSimplex.sample(prng, out)
+            })
+        },
+    },
+    VectorDistribution {
+        name: "annulus",
+        params: &[
+            ("inner", Support::NonNegative),
+            ("outer", Support::NonNegative),
+        ],
+        repeated: false,
+        build: |p| {
+            if p[0] > p[1] {
+                return Err("the inner radius of annulus must not exceed the outer radius".into());
+            }
+            let annulus = Annulus::new(p[0], p[1]);
+            VectorSampler::new(2, move |prng, out| {
+                out.copy_from_slice(&annulus.sample(prng))
+            })
+        },
+    },
+    VectorDistribution {
+        name: "triangle",
+        params: &[("coordinates of the 3 vertices in 2D or 3D", Support::Real)],
+        repeated: true,
+        build: |p| match *p {
+            [ax, ay, bx, by, cx, cy] => {
+                let triangle = Triangle::new([ax, ay], [bx, by], [cx, cy]);
+                VectorSampler::new(2, move |prng, out| {
+                    out.copy_from_slice(&triangle.sample(prng))
+                })
+            }
+            [ax, ay, az, bx, by, bz, cx, cy, cz] => {
+                let triangle = Triangle::new([ax, ay, az], [bx, by, bz], [cx, cy, cz]);
+                VectorSampler::new(3, move |prng, out| {
+                    out.copy_from_slice(&triangle.sample(prng))
+                })
+            }
+            _ => Err("triangle takes 6 or 9 coordinates".into()),
+        },
+    },
+    VectorDistribution {
+        name: "polygon",
+        params: &[("x and y of each vertex", Support::Real)],
+        repeated: true,
+        build: |p| {
+            if p.len() % 2 != 0 {
+                return Err("polygon takes an x and a y for each vertex".into());
+            }
+            let vertices: Vec<[f64; 2]> = p.chunks_exact(2).map(|v| [v[0], v[1]]).collect();
+            let polygon = Polygon::new(&vertices).ok_or(
+                "polygon needs at least 3 vertices of a simple polygon with a non-zero area",
+            )?;
+            VectorSampler::new(2, move |prng, out| {
+                out.copy_from_slice(&polygon.sample(prng))
+            })
+        },
+    },
+    VectorDistribution {
+        name: "quaternion",
+        params: &[],
+        repeated: false,
+        build: |_| {
+            VectorSampler::new(4, |prng, out| {
+                out.copy_from_slice(&prng.random_quaternion())
+            })
+        },
+    },
+    VectorDistribution {
+        name: "rotation_3d",
+        params: &[],
+        repeated: false,
+        build: |_| {
+            VectorSampler::new(9, |prng, out| {
+                out.copy_from_slice(prng.random_rotation_3d().as_flattened());
+            })
+        },
+    },
+    VectorDistribution {
+        name: "orthogonal",
+        params: &[("n", Support::Count)],
+        repeated: false,
+        build: |p| {
+            let n = dimension(p[0], 256)?;
+            VectorSampler::new(n * n, move |prng, out| prng.random_orthogonal(n, out))
+        },
+    },
+];
+
+enum Format {
+    Csv,
+    F64Le,
+}
+
+/// A distribution from either table, found by name
+#[derive(Clone, Copy)]
+enum Selected {
+    Scalar(&'static Distribution),
+    Vector(&'static VectorDistribution),
+}
+
+impl Selected {
+    fn find(name: &str) -> Option<Self> {
+        let scalar = DISTRIBUTIONS
+            .iter()
+            .find(|distribution| distribution.name == name);
+        let vector = VECTOR_DISTRIBUTIONS
+            .iter()
+            .find(|distribution| distribution.name == name);
+        scalar
+            .map(Selected::Scalar)
+            .or(vector.map(Selected::Vector))
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Selected::Scalar(distribution) => distribution.name,
+            Selected::Vector(distribution) => distribution.name,
+        }
+    }
+
+    const fn params(self) -> &'static [(&'static str, Support)] {
+        match self {
+            Selected::Scalar(distribution) => distribution.params,
+            Selected::Vector(distribution) => distribution.params,
+        }
+    }
+
+    const fn repeated(self) -> bool {
+        match self {
+            Selected::Scalar(_) => false,
+            Selected::Vector(distribution) => distribution.repeated,
+        }
+    }
+
+    /// Describes the parameters for ``--list``
+    fn describe(self) -> String {
+        let params: Vec<String> = self
+            .params()
+            .iter()
+            .enumerate()
+            .map(|(i, (name, support))| {
+                let dots = if self.repeated() && i + 1 == self.params().len() {
+                    "..."
+                } else {
+                    ""
+                };
+                format!("{name}{dots} {}", support.describe())
+            })
+            .collect();
+        if params.is_empty() {
+            self.name().to_string()
+        } else {
+            format!("{}: {}", self.name(), params.join(", "))
+        }
+    }
+}
+
+/// How the samples are drawn, with the parameters already validated
+enum Sampler {
+    Scalar(fn(&mut Prng, &[f64]) -> Sample, Vec<f64>),
+    Vector(VectorSampler),
+}
+
+struct Options {
+    name: &'static str,
+    sampler: Sampler,
+    n: u64,
+    seed: Option<u64>,
+    algorithm: String,
+    format: Format,
+    output: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    if args.iter().any(|arg| arg == "--list") {
+        let scalar = DISTRIBUTIONS.iter().map(Selected::Scalar);
+        for distribution in scalar.chain(VECTOR_DISTRIBUTIONS.iter().map(Selected::Vector)) {
+            println!("{}", distribution.describe());
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let mut options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("smolprng-sample: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let Some(generator) = registry::from_name(&options.algorithm, options.seed) else {
+        eprintln!(
+            "smolprng-sample: unknown algorithm '{}', see smolprng-dump --list",
+            options.algorithm
+        );
+        return ExitCode::from(2);
+    };
+
+    let result = match options.output.take() {
+        Some(path) => {
+            File::create(path).and_then(|file| write_samples(generator, &mut options, file))
+        }
+        None => write_samples(generator, &mut options, io::stdout().lock()),
+    };
+
+    match result {
+        Err(err) if err.kind() != io::ErrorKind::BrokenPipe => {
+            eprintln!("smolprng-sample: {err}");
+            ExitCode::FAILURE
+        }
+        _ => ExitCode::SUCCESS,
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut distribution = None;
+    let mut params = None;
+    let mut n = None;
+    let mut seed = None;
+    let mut algorithm = String::from("jsf64");
+    let mut format = Format::Csv;
+    let mut output = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{arg} needs a value"));
+
+        match arg.as_str() {
+            "--dist" => {
+                let name = value()?;
+                distribution = Some(
+                    Selected::find(name)
+                        .ok_or_else(|| format!("unknown distribution '{name}', see --list"))?,
+                );
+            }
+            "--params" => {
+                params = Some(
+                    value()?
+                        .split(',')
+                        .map(|param| {
+                            param
+                                .trim()
+                                .parse::<f64>()
+                                .map_err(|_| format!("invalid parameter '{param}'"))
+                        })
+                        .collect::<Result<Vec<f64>, String>>()?,
+                );
+            }
+            "--n" => n = Some(parse_count(value()?)?),
+            "--seed" => seed = Some(parse_seed(value()?)?),
+            "--algo" => algorithm.clone_from(value()?),
+            "--format" => {
+                format = match value()?.as_str() {
+                    "csv" => Format::Csv,
+                    "f64le" => Format::F64Le,
+                    other => return Err(format!("unknown format '{other}'")),
+                }
+            }
+            "--output" => output = Some(value()?.clone()),
+            "-h" | "--help" => return Err("help requested".into()),
+            other => return Err(format!("unexpected argument '{other}'")),
+        }
+    }
+
+    let distribution: Selected = distribution.ok_or("missing --dist")?;
+    let params = params.unwrap_or_default();
+    let expected = distribution.params();
+
+    if distribution.repeated() && params.len() < expected.len() {
+        return Err(format!(
+            "{} takes at least {} parameters, got {}",
+            distribution.name(),
+            expected.len(),
+            params.len()
+        ));
+    }
+    if !distribution.repeated() && params.len() != expected.len() {
+        return Err(format!(
+            "{} takes {} parameters, got {}",
+            distribution.name(),
+            expected.len(),
+            params.len()
+        ));
+    }
+
+    // the last parameter of a repeated distribution checks every remaining value
+    for (i, value) in params.iter().enumerate() {
+        let (name, support) = expected[i.min(expected.len() - 1)];
+        if !support.contains(*value) {
+            return Err(format!(
+                "parameter {name} of {} must be {}, got {value}",
+                distribution.name(),
+                support.describe()
+            ));
+        }
+    }
+
+    let sampler = match distribution {
+        Selected::Scalar(distribution) => Sampler::Scalar(distribution.sample, params),
+        Selected::Vector(distribution) => Sampler::Vector((distribution.build)(&params)?),
+    };
+
+    Ok(Options {
+        name: distribution.name(),
+        sampler,
+        n: n.ok_or("missing --n")?,
+        seed,
+        algorithm,
+        format,
+        output,
+    })
+}
+
+/// Parses a sample count, accepting scientific notation such as ``1e6``
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn parse_count(value: &str) -> Result<u64, String> {
+    if let Ok(n) = value.parse::<u64>() {
+        return Ok(n);
+    }
+    match value.parse::<f64>() {
+        Ok(n) if Support::Count.contains(n) => Ok(n as u64),
+        _ => Err(format!("invalid sample count '{value}'")),
+    }
+}
+
+fn parse_seed(value: &str) -> Result<u64, String> {
+    let parsed = match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse(),
+    };
+    parsed.map_err(|_| format!("invalid seed '{value}'"))
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn write_samples(
+    generator: Box<dyn DynAlgorithm>,
+    options: &mut Options,
+    out: impl Write,
+) -> io::Result<()> {
+    let mut prng = PRNG { generator };
+    let mut out = BufWriter::with_capacity(1 << 16, out);
+
+    match &mut options.sampler {
+        Sampler::Scalar(sample, params) => {
+            if let Format::Csv = options.format {
+                writeln!(out, "{}", options.name)?;
+            }
+
+            for _ in 0..options.n {
+                match (&options.format, sample(&mut prng, params)) {
+                    (Format::Csv, Sample::Real(x)) => writeln!(out, "{x}")?,
+                    (Format::Csv, Sample::Count(k)) => writeln!(out, "{k}")?,
+                    (Format::F64Le, Sample::Real(x)) => out.write_all(&x.to_le_bytes())?,
+                    (Format::F64Le, Sample::Count(k)) => {
+                        out.write_all(&(k as f64).to_le_bytes())?
+                    }
+                }
+            }
+        }
+        Sampler::Vector(sampler) => {
+            let mut row = vec![0f64; sampler.dim];
+
+            if let Format::Csv = options.format {
+                let header: Vec<String> = (0..sampler.dim)
+                    .map(|i| format!("{}_{i}", options.name))
+                    .collect();
+                writeln!(out, "{}", header.join(","))?;
+            }
+
+            for _ in 0..options.n {
+                (sampler.sample)(&mut prng, &mut row);
+                match options.format {
+                    Format::Csv => {
+                        for (i, x) in row.iter().enumerate() {
+                            let separator = if i + 1 == row.len() { '\n' } else { ',' };
+                            write!(out, "{x}{separator}")?;
+                        }
+                    }
+                    Format::F64Le => {
+                        for x in &row {
+                            out.write_all(&x.to_le_bytes())?;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    out.flush()
+}
@@ -0,0 +1,108 @@
+//! This module contains the `CryptoAlgorithm` marker trait and the methods that are only available on
+//! cryptographically secure generators
+//!
+//! Secrets such as tokens and passwords must not be predictable from other outputs, which only holds for a secure
+//! algorithm with a secret key. Restricting these methods to `CryptoAlgorithm` makes generating a secret with a
+//! statistical generator a compile error.
+//!
+//! ```rust
+//! use smolprng::{SecureChaCha20, PRNG};
+//!
+//! // the key must come from a secure source, such as the operating system
+//! let mut prng = PRNG{generator: SecureChaCha20::new([42u8; 32], 0)};
+//! let token: [u8; 32] = prng.gen_token_bytes();
+//! ```
+//!
+//! ```compile_fail
+//! use smolprng::{LCG, PRNG};
+//!
+//! let mut prng = PRNG{generator: LCG::default()};
+//! let token: [u8; 32] = prng.gen_token_bytes();
+//! ```
+//!
+//! `ChaCha` seeded with `Default` or `From` is keyed from a public seed, so only `SecureChaCha` is accepted.
+//!
+//! ```compile_fail
+//! use smolprng::{ChaCha20, PRNG};
+//!
+//! let mut prng = PRNG{generator: ChaCha20::default()};
+//! let token: [u8; 32] = prng.gen_token_bytes();
+//! ```
+
+use crate::smol_core::{Algorithm, PRNG};
+use crate::AlgorithmOutput;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// A marker for algorithms whose outputs can not be predicted by an adversary that does not know the seed
+///
+/// Only implement this for cryptographically secure algorithms, the secure-only methods of `PRNG` rely on it.
+pub trait CryptoAlgorithm: Algorithm {}
+
+/// The ASCII letters and digits
+pub const ALPHANUMERIC: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+/// The printable ASCII characters other than space
+pub const ASCII_GRAPHIC: &str =
+    "!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstuvwxyz{|}~";
+
+impl<T: CryptoAlgorithm> PRNG<T> {
+    /// Generates ``N`` random bytes for use as a secret token or key
+    ///
+    /// The bytes are whole outputs in little endian byte order, so with `SecureChaCha` they are the keystream.
+    pub fn gen_token_bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut token = [0u8; N];
+        for chunk in token.chunks_mut(T::Output::SIZE) {
+            let bytes = self.generator.gen().cast_to_u128().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+        token
+    }
+
+    /// Generates a password of `length` characters, each chosen uniformly and independently from `alphabet`
+    ///
+    /// The password has ``length * log2(alphabet length)`` bits of entropy.
+    ///
+    /// # Panics
+    /// Panics if `alphabet` is empty
+    ///
+    /// ```rust
+    /// use smolprng::{SecureChaCha20, PRNG, ALPHANUMERIC};
+    /// let mut prng = PRNG{generator: SecureChaCha20::new([42u8; 32], 0)};
+    ///
+    /// let password = prng.gen_password(20, ALPHANUMERIC);
+    /// assert_eq!(password.len(), 20);
+    /// ```
+    pub fn gen_password(&mut self, length: usize, alphabet: &str) -> String {
+        let chars: Vec<char> = alphabet.chars().collect();
+        assert!(!chars.is_empty(), "alphabet must not be empty");
+
+        (0..length)
+            .map(|_| chars[self.gen_u64_in_range(chars.len() as u64) as usize])
+            .collect()
+    }
+
+    /// Generates the 16 bytes of a random version 4 UUID, as specified in RFC 4122
+    ///
+    /// 122 bits are random, the remaining bits mark the version and the variant.
+    pub fn gen_uuid_v4_secure(&mut self) -> [u8; 16] {
+        let mut uuid: [u8; 16] = self.gen_token_bytes();
+        uuid[6] = (uuid[6] & 0x0f) | 0x40;
+        uuid[8] = (uuid[8] & 0x3f) | 0x80;
+        uuid
+    }
+}
+
+/// Formats the bytes of a UUID in the hyphenated form, such as ``"123e4567-e89b-42d3-a456-426614174000"``
+pub fn uuid_to_string(uuid: &[u8; 16]) -> String {
+    let mut out = String::with_capacity(36);
+    for (i, byte) in uuid.iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            out.push('-');
+        }
+        // writing to a String can not fail
+        let _ = write!(out, "{byte:02x}");
+    }
+    out
+}
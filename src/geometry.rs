@@ -0,0 +1,349 @@
+//! This module contains uniform samplers over geometric objects, such as spheres, balls, simplices and polygons
+//!
+//! Every sampler has a method that fills a caller provided slice or returns a fixed size array, neither of which
+//! allocates.
+
+use crate::smol_core::{Algorithm, PRNG};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::f64::consts::PI;
+
+/// Uniform distribution on the unit circle in 2D
+pub struct UnitCircle;
+
+impl UnitCircle {
+    /// Samples a point on the unit circle
+    ///
+    /// Uses von Neumann's method of squaring a point in the unit disc, which needs no square roots or trigonometry
+    pub fn sample<T: Algorithm>(&self, prng: &mut PRNG<T>) -> [f64; 2] {
+        loop {
+            let (u, v) = prng.disc2d();
+            let r2 = u * u + v * v;
+            if r2 > 0f64 {
+                return [(u * u - v * v) / r2, 2f64 * u * v / r2];
+            }
+        }
+    }
+}
+
+/// Uniform distribution on the surface of the unit sphere in n dimensions
+///
+/// ```rust
+/// use smolprng::{JsfLarge, UnitSphere, PRNG};
+/// let mut prng = PRNG{generator: JsfLarge::default()};
+///
+/// let [x, y, z] = UnitSphere.sample_array(&mut prng);
+/// assert!((x * x + y * y + z * z - 1.0).abs() < 1e-12);
+///
+/// let mut p = [0f64; 10];
+/// UnitSphere.sample(&mut prng, &mut p);
+/// ```
+#[cfg(feature = "std")]
+pub struct UnitSphere;
+
+#[cfg(feature = "std")]
+impl UnitSphere {
+    /// Samples a point on the sphere embedded in ``out.len()`` dimensions
+    ///
+    /// 2D and 3D use rejection from the disc, higher dimensions normalize a vector of normal samples
+    pub fn sample<T: Algorithm>(&self, prng: &mut PRNG<T>, out: &mut [f64]) {
+        match out.len() {
+            0 => {}
+            1 => out[0] = if prng.gen_bool() { 1f64 } else { -1f64 },
+            2 => out.copy_from_slice(&UnitCircle.sample(prng)),
+            3 => {
+                // Marsaglia, "Choosing a Point from the Surface of a Sphere" 1972
+                let (u, v) = prng.disc2d();
+                let s = u * u + v * v;
+                let r = 2f64 * (1f64 - s).sqrt();
+                out.copy_from_slice(&[u * r, v * r, 1f64 - 2f64 * s]);
+            }
+            _ => loop {
+                for x in out.iter_mut() {
+                    *x = prng.normal();
+                }
+                let norm = out.iter().map(|x| x * x).sum::<f64>().sqrt();
+                if norm > 0f64 {
+                    for x in out.iter_mut() {
+                        *x /= norm;
+                    }
+                    return;
+                }
+            },
+        }
+    }
+
+    /// Samples a point on the sphere embedded in ``N`` dimensions
+    pub fn sample_array<T: Algorithm, const N: usize>(&self, prng: &mut PRNG<T>) -> [f64; N] {
+        let mut out = [0f64; N];
+        self.sample(prng, &mut out);
+        out
+    }
+}
+
+/// Uniform distribution on the interior of the unit ball in n dimensions
+#[cfg(feature = "std")]
+pub struct UnitBall;
+
+#[cfg(feature = "std")]
+impl UnitBall {
+    /// Samples a point in the ball in ``out.len()`` dimensions
+    ///
+    /// Up to 3D this is rejection from the enclosing cube, higher dimensions scale a point on the sphere by
+    /// ``U^(1/n)`` as the acceptance rate of rejection decays factorially
+    pub fn sample<T: Algorithm>(&self, prng: &mut PRNG<T>, out: &mut [f64]) {
+        let n = out.len();
+
+        if n <= 3 {
+            loop {
+                for x in out.iter_mut() {
+                    *x = prng.gen_f64() * 2f64 - 1f64;
+                }
+                if out.iter().map(|x| x * x).sum::<f64>() <= 1f64 {
+                    return;
+                }
+            }
+        }
+
+        UnitSphere.sample(prng, out);
+        #[allow(clippy::cast_precision_loss)]
+        let r = prng.gen_f64().powf(1f64 / n as f64);
+        for x in out.iter_mut() {
+            *x *= r;
+        }
+    }
+
+    /// Samples a point in the ball in ``N`` dimensions
+    pub fn sample_array<T: Algorithm, const N: usize>(&self, prng: &mut PRNG<T>) -> [f64; N] {
+        let mut out = [0f64; N];
+        self.sample(prng, &mut out);
+        out
+    }
+}
+
+/// Uniform distribution on the standard simplex, the points with non-negative coordinates that sum to one
+#[cfg(feature = "std")]
+pub struct UnitSimplex;
+
+#[cfg(feature = "std")]
+impl UnitSimplex {
+    /// Samples a point on the simplex in ``out.len()`` dimensions
+    ///
+    /// Normalized standard exponential samples are uniform on the simplex
+    pub fn sample<T: Algorithm>(&self, prng: &mut PRNG<T>, out: &mut [f64]) {
+        loop {
+            for x in out.iter_mut() {
+                *x = -(1f64 - prng.gen_f64()).ln();
+            }
+            let sum: f64 = out.iter().sum();
+            if sum > 0f64 {
+                for x in out.iter_mut() {
+                    *x /= sum;
+                }
+                return;
+            }
+        }
+    }
+
+    /// Samples a point on the simplex in ``N`` dimensions
+    pub fn sample_array<T: Algorithm, const N: usize>(&self, prng: &mut PRNG<T>) -> [f64; N] {
+        let mut out = [0f64; N];
+        self.sample(prng, &mut out);
+        out
+    }
+}
+
+/// Uniform distribution on the 2D annulus between two radii centered at the origin
+#[cfg(feature = "std")]
+pub struct Annulus {
+    inner: f64,
+    outer: f64,
+}
+
+#[cfg(feature = "std")]
+impl Annulus {
+    /// Constructs the annulus with ``0 <= inner <= outer``
+    ///
+    /// # Panics
+    /// Panics if the radii are not finite or not ordered
+    pub fn new(inner: f64, outer: f64) -> Self {
+        assert!(
+            inner.is_finite() && outer.is_finite() && 0f64 <= inner && inner <= outer,
+            "radii must be finite and satisfy 0 <= inner <= outer"
+        );
+        Annulus { inner, outer }
+    }
+
+    /// Samples a point in the annulus
+    ///
+    /// The radius is sampled by inverting the area CDF so the density is uniform over the area
+    pub fn sample<T: Algorithm>(&self, prng: &mut PRNG<T>) -> [f64; 2] {
+        let inner2 = self.inner * self.inner;
+        let r = (inner2 + prng.gen_f64() * (self.outer * self.outer - inner2)).sqrt();
+        let theta = 2f64 * PI * prng.gen_f64();
+        [r * theta.cos(), r * theta.sin()]
+    }
+}
+
+/// Uniform distribution on a triangle embedded in ``N`` dimensions
+pub struct Triangle<const N: usize> {
+    vertices: [[f64; N]; 3],
+}
+
+impl<const N: usize> Triangle<N> {
+    /// Constructs the triangle from its three vertices
+    pub const fn new(a: [f64; N], b: [f64; N], c: [f64; N]) -> Self {
+        Triangle {
+            vertices: [a, b, c],
+        }
+    }
+
+    /// Samples a point in the triangle
+    ///
+    /// Samples the parallelogram spanned by the edges and reflects points in the far half back into the triangle
+    pub fn sample<T: Algorithm>(&self, prng: &mut PRNG<T>) -> [f64; N] {
+        let mut r1 = prng.gen_f64();
+        let mut r2 = prng.gen_f64();
+        if r1 + r2 > 1f64 {
+            r1 = 1f64 - r1;
+            r2 = 1f64 - r2;
+        }
+
+        let [a, b, c] = &self.vertices;
+        let mut out = [0f64; N];
+        for i in 0..N {
+            out[i] = a[i] + r1 * (b[i] - a[i]) + r2 * (c[i] - a[i]);
+        }
+        out
+    }
+}
+
+/// Uniform distribution on a simple polygon in 2D
+///
+/// The polygon is triangulated by ear clipping on construction, then sampling picks a triangle by area and
+/// samples within it.
+///
+/// ```rust
+/// use smolprng::{JsfLarge, Polygon, PRNG};
+/// let mut prng = PRNG{generator: JsfLarge::default()};
+///
+/// // an L shaped polygon
+/// let l_shape = Polygon::new(&[[0.0, 0.0], [2.0, 0.0], [2.0, 1.0], [1.0, 1.0], [1.0, 2.0], [0.0, 2.0]]).unwrap();
+/// let [x, y] = l_shape.sample(&mut prng);
+/// assert!(x <= 1.0 || y <= 1.0);
+/// ```
+pub struct Polygon {
+    triangles: Vec<Triangle<2>>,
+    cumulative_area: Vec<f64>,
+}
+
+impl Polygon {
+    /// Triangulates a simple polygon given its vertices in either winding order
+    ///
+    /// Returns ``None`` if there are fewer than three vertices, the area is zero, or two edges cross. Edges that
+    /// only touch, such as a vertex lying on another edge, are not detected.
+    pub fn new(vertices: &[[f64; 2]]) -> Option<Self> {
+        let n = vertices.len();
+        if n < 3 {
+            return None;
+        }
+
+        // neighbouring edges share a vertex so they can not cross, every other pair is checked
+        let edge = |i: usize| [vertices[i], vertices[(i + 1) % n]];
+        let self_intersects = (0..n).any(|i| {
+            (i + 2..n)
+                .filter(|&j| (j + 1) % n != i)
+                .any(|j| edges_cross(edge(i), edge(j)))
+        });
+        if self_intersects {
+            return None;
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        let area2: f64 = (0..n)
+            .map(|i| cross(vertices[i], vertices[(i + 1) % n], [0f64, 0f64]))
+            .sum();
+
+        if area2 == 0f64 || !area2.is_finite() {
+            return None;
+        }
+
+        // ear clipping assumes counterclockwise winding
+        if area2 < 0f64 {
+            order.reverse();
+        }
+
+        let mut triangles = Vec::with_capacity(n - 2);
+        let mut cumulative_area = Vec::with_capacity(n - 2);
+        let mut total = 0f64;
+
+        while order.len() > 3 {
+            let len = order.len();
+            let corner = |i: usize| [order[(i + len - 1) % len], order[i], order[(i + 1) % len]];
+
+            // a vertex collinear with its neighbours adds no area, but lies on the boundary of the ears next to it
+            let flat = (0..len)
+                .map(corner)
+                .position(|[a, b, c]| cross(vertices[a], vertices[b], vertices[c]) == 0f64);
+            if let Some(i) = flat {
+                order.remove(i);
+                continue;
+            }
+
+            let ear = (0..len).map(corner).find(|&[a, b, c]| {
+                cross(vertices[a], vertices[b], vertices[c]) > 0f64
+                    && order
+                        .iter()
+                        .filter(|&&j| j != a && j != b && j != c)
+                        .all(|&j| !in_triangle(vertices[j], vertices[a], vertices[b], vertices[c]))
+            })?;
+
+            let [a, b, c] = ear.map(|j| vertices[j]);
+            total += 0.5 * cross(a, b, c);
+            triangles.push(Triangle::new(a, b, c));
+            cumulative_area.push(total);
+            order.retain(|&j| j != ear[1]);
+        }
+
+        let [a, b, c] = [order[0], order[1], order[2]].map(|j| vertices[j]);
+        total += 0.5 * cross(a, b, c).abs();
+        triangles.push(Triangle::new(a, b, c));
+        cumulative_area.push(total);
+
+        Some(Polygon {
+            triangles,
+            cumulative_area,
+        })
+    }
+
+    /// The area of the polygon
+    pub fn area(&self) -> f64 {
+        self.cumulative_area[self.cumulative_area.len() - 1]
+    }
+
+    /// Samples a point in the polygon
+    pub fn sample<T: Algorithm>(&self, prng: &mut PRNG<T>) -> [f64; 2] {
+        let u = prng.gen_f64() * self.area();
+        let i = self
+            .cumulative_area
+            .partition_point(|&a| a <= u)
+            .min(self.triangles.len() - 1);
+        self.triangles[i].sample(prng)
+    }
+}
+
+/// Twice the signed area of the triangle abc, positive when counterclockwise
+fn cross(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// Tests if the segments ab and cd cross at a point inside both of them
+fn edges_cross([a, b]: [[f64; 2]; 2], [c, d]: [[f64; 2]; 2]) -> bool {
+    let opposite = |x: f64, y: f64| (x < 0f64 && y > 0f64) || (x > 0f64 && y < 0f64);
+    opposite(cross(a, b, c), cross(a, b, d)) && opposite(cross(c, d, a), cross(c, d, b))
+}
+
+/// Tests if p is inside or on the boundary of the counterclockwise triangle abc
+fn in_triangle(p: [f64; 2], a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> bool {
+    cross(a, b, p) >= 0f64 && cross(b, c, p) >= 0f64 && cross(c, a, p) >= 0f64
+}
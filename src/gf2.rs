@@ -0,0 +1,186 @@
+//! Polynomial arithmetic over GF(2), used to jump linear generators ahead
+//!
+//! The state transition of a generator that only uses xor, shifts and rotations is a matrix ``T`` over GF(2). With
+//! ``p`` the characteristic polynomial of ``T``, jumping ``steps`` ahead is applying ``q(T)`` to the state where
+//! ``q = x^steps mod p``, which costs a few polynomial squarings and one pass of Horner's rule instead of ``steps``
+//! calls to the generator.
+//!
+//! Method from "Efficient Jump Ahead for F2-Linear Random Number Generators" - Hiroshi Haramoto, Makoto Matsumoto,
+//! Takuji Nishimura, François Panneton, Pierre L'Ecuyer INFORMS Journal on Computing 2008
+//! DOI: <https://doi.org/10.1287/ijoc.1070.0251/>
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A polynomial over GF(2), bit ``i`` of the words is the coefficient of ``x^i``
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gf2Poly {
+    words: Vec<u64>,
+}
+
+impl Gf2Poly {
+    /// The zero polynomial with room for ``bits`` coefficients
+    fn zero(bits: usize) -> Self {
+        Self {
+            words: vec![0; bits / 64 + 1],
+        }
+    }
+
+    /// The polynomial ``1`` with room for ``bits`` coefficients
+    fn one(bits: usize) -> Self {
+        let mut poly = Self::zero(bits);
+        poly.words[0] = 1;
+        poly
+    }
+
+    /// The polynomial with the coefficients in the bits of ``words``
+    pub fn from_words(words: &[u64]) -> Self {
+        Self {
+            words: words.to_vec(),
+        }
+    }
+
+    /// The coefficient of ``x^i``
+    pub fn coefficient(&self, i: usize) -> bool {
+        self.words
+            .get(i / 64)
+            .is_some_and(|word| word >> (i % 64) & 1 == 1)
+    }
+
+    /// The degree of the polynomial, or `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.words
+            .iter()
+            .rposition(|word| *word != 0)
+            .map(|i| 64 * i + 63 - self.words[i].leading_zeros() as usize)
+    }
+
+    /// Adds ``other * x^shift`` to the polynomial, coefficients past the end of the storage are dropped
+    fn add_shifted(&mut self, other: &Self, shift: usize) {
+        let (offset, bits) = (shift / 64, shift % 64);
+        let used = other
+            .words
+            .iter()
+            .rposition(|word| *word != 0)
+            .map_or(0, |i| i + 1);
+        let source = &other.words[..used];
+        let target = self.words.get_mut(offset..).unwrap_or_default();
+
+        if bits == 0 {
+            for (word, value) in target.iter_mut().zip(source) {
+                *word ^= value;
+            }
+        } else {
+            for (word, value) in target.iter_mut().zip(source) {
+                *word ^= value << bits;
+            }
+            for (word, value) in target.iter_mut().skip(1).zip(source) {
+                *word ^= value >> (64 - bits);
+            }
+        }
+    }
+
+    /// Reduces the polynomial modulo ``modulus`` of degree ``degree``
+    fn reduce(&mut self, modulus: &Self, degree: usize) {
+        // adding the shifted modulus only changes coefficients at or below the one it cancels
+        for i in (degree..=self.degree().unwrap_or(0)).rev() {
+            if self.coefficient(i) {
+                self.add_shifted(modulus, i - degree);
+            }
+        }
+    }
+}
+
+/// Spreads the bits of ``x`` to the even bits of the result, which squares a polynomial over GF(2)
+const fn spread(x: u32) -> u64 {
+    let mut x = x as u64;
+    x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+    x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+/// The minimal polynomial of a linear recurring bit sequence by the Berlekamp-Massey algorithm
+///
+/// For a generator with a primitive characteristic polynomial of degree ``n``, any bit of its outputs gives the
+/// characteristic polynomial from ``2n`` outputs. The jumps use precomputed polynomials that this checks, and the
+/// linear complexity test of the ``testing`` feature is the degree of the result.
+#[cfg(any(test, feature = "testing"))]
+pub fn minimal_polynomial(sequence: &[bool]) -> Gf2Poly {
+    let n = sequence.len();
+
+    // the sequence is stored reversed, so the window s_k, s_(k-1), ... is read upwards from bit n - 1 - k
+    // with a word of padding so a window never reads past the end
+    let mut reversed = vec![0u64; n / 64 + 3];
+    for (i, _) in sequence.iter().enumerate().filter(|(_, bit)| **bit) {
+        let j = n - 1 - i;
+        reversed[j / 64] |= 1 << (j % 64);
+    }
+
+    // the connection polynomial is c(x) = 1 + c_1 x + ... + c_l x^l with s_k = c_1 s_(k-1) + ... + c_l s_(k-l)
+    let mut connection = Gf2Poly::one(n + 1);
+    let mut previous = Gf2Poly::one(n + 1);
+    let mut scratch = Gf2Poly::zero(n + 1);
+    let mut length = 0;
+    let mut gap = 1;
+
+    for k in 0..n {
+        let (index, bits) = ((n - 1 - k) / 64, (n - 1 - k) % 64);
+        let window = reversed[index..].iter().zip(&reversed[index + 1..]);
+        let discrepancy = connection.words[..=length / 64].iter().zip(window).fold(
+            0,
+            |acc, (word, (low, high))| {
+                // the high word is shifted in two steps so a zero bit offset shifts it out entirely
+                acc ^ (word & ((low >> bits) | ((high << 1) << (63 - bits))))
+            },
+        );
+
+        if discrepancy.count_ones() % 2 == 0 {
+            gap += 1;
+        } else if 2 * length <= k {
+            scratch.words.copy_from_slice(&connection.words);
+            connection.add_shifted(&previous, gap);
+            core::mem::swap(&mut previous, &mut scratch);
+            length = k + 1 - length;
+            gap = 1;
+        } else {
+            connection.add_shifted(&previous, gap);
+            gap += 1;
+        }
+    }
+
+    // the minimal polynomial is the reciprocal of the connection polynomial
+    let mut minimal = Gf2Poly::zero(length + 1);
+    for i in (0..=length).filter(|i| connection.coefficient(*i)) {
+        minimal.words[(length - i) / 64] |= 1 << ((length - i) % 64);
+    }
+    minimal
+}
+
+/// Computes ``x^exponent mod modulus`` by square and multiply
+pub fn x_pow_mod(exponent: u128, modulus: &Gf2Poly) -> Gf2Poly {
+    let degree = modulus.degree().expect("the modulus is not zero");
+    // room for the square of a polynomial of degree below the modulus, times x
+    let bits = 128 * (degree / 64 + 1);
+    let mut result = Gf2Poly::one(bits);
+    result.reduce(modulus, degree);
+
+    for bit in (0..(128 - exponent.leading_zeros())).rev() {
+        let mut square = Gf2Poly::zero(bits);
+        for (i, word) in result.words.iter().enumerate().take(degree / 64 + 1) {
+            square.words[2 * i] = spread(*word as u32);
+            square.words[2 * i + 1] = spread((word >> 32) as u32);
+        }
+        result = square;
+
+        if exponent >> bit & 1 == 1 {
+            let mut carry = 0;
+            for word in &mut result.words {
+                (*word, carry) = ((*word << 1) | carry, *word >> 63);
+            }
+        }
+        result.reduce(modulus, degree);
+    }
+    result
+}
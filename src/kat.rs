@@ -0,0 +1,858 @@
+//! Known answer tests, every algorithm is run from a fixed state and compared against the first outputs of its
+//! reference implementation
+//!
+//! The reference outputs were produced by the published C sources linked in the documentation of each algorithm,
+//! with the state words loaded in the order of the ``data`` field. Algorithms with no published reference are
+//! pinned to their current output as regression vectors, so that any change to them is deliberate and comes with a
+//! new ``REVISION``.
+
+use crate::*;
+
+const S64: [u64; 4] = [
+    0x0123_4567_89ab_cdef,
+    0xfedc_ba98_7654_3210,
+    0x0f1e_2d3c_4b5a_6978,
+    0x8796_a5b4_c3d2_e1f0,
+];
+
+const S32: [u32; 4] = [0x0123_4567, 0x89ab_cdef, 0xfedc_ba98, 0x7654_3210];
+
+macro_rules! kat {
+    ($fn_name:ident, $gen_type:ident, $revision:expr, $state:expr, [$($expected:expr),+ $(,)?]) => {
+        #[test]
+        fn $fn_name() {
+            let mut generator = $gen_type { data: $state };
+            let expected = [$($expected),+];
+            for (i, value) in expected.iter().enumerate() {
+                assert_eq!(generator.gen(), *value, "output {} of {}", i, stringify!($gen_type));
+            }
+            assert_eq!(<$gen_type as Algorithm>::REVISION, $revision);
+        }
+    };
+}
+
+// reference vectors from the xoshiro / xoroshiro sources at https://prng.di.unimi.it/
+
+kat! {kat_xoshiro_256_super_star, XoShiro256SuperStar, 2, S64, [
+    0x6666_6666_6666_6c65, 0xd906_3360_8dba_e0aa, 0x3198_d392_d660_bce0, 0x5a49_a1c6_7304_ca22,
+    0x5f78_a398_f0b2_4a01, 0x5432_b677_801d_1eaa, 0x5bd2_b1af_690f_bb72, 0x2519_d063_7595_f2f3,
+]}
+
+kat! {kat_xoshiro_256_plus_plus, XoShiro256PlusPlus, 2, S64, [
+    0x8f4a_04bf_7970_2ae4, 0x32a9_63a5_9bd6_90c3, 0xcdb8_1ce0_5b96_9bee, 0x1e65_45c9_2421_5805,
+    0x2dc0_87de_d5b8_f4db, 0x7b25_c30e_bc2e_e695, 0xa2c9_f12e_f23c_72af, 0xa9af_73f7_990f_105a,
+]}
+
+kat! {kat_xoshiro_256_plus, XoShiro256Plus, 1, S64, [
+    0x88b9_eb1c_4d7e_afdf, 0x52e5_6974_8012_b4bf, 0x52fb_6cf5_872a_7d20, 0x2fd6_6cf9_dbae_7f8b,
+    0x9bfc_1e0a_09b6_1cc8, 0xbea5_6ad2_4bbe_c9f4, 0x932e_5171_513e_72ee, 0xace1_148a_b2a2_77a9,
+]}
+
+kat! {kat_xoshiro_128_plus_plus, XoShiro128PlusPlus, 1, S32, [
+    0xbcdf_0122, 0x6d3a_0697, 0x6af1_59e1, 0x592f_c2e1, 0xa573_6377, 0x1030_b172, 0x7c11_8fc4, 0x73f7_bca8,
+]}
+
+kat! {kat_xoshiro_128_plus, XoShiro128Plus, 1, S32, [
+    0x7777_7777, 0xfedc_ba97, 0xd5e6_f3c4, 0x289e_7641, 0x33ce_b29d, 0x2fa8_988a, 0xee54_b31f, 0x55c1_5015,
+]}
+
+kat! {kat_xoshiro_128_super_star, XoShiro128SuperStar, 1, S32, [
+    0x9999_8498, 0x6666_695f, 0xcce4_f862, 0xc669_8edf, 0x9525_9c18, 0xd659_c6f7, 0xe045_0a43, 0xddef_3452,
+]}
+
+/// The state of the 512 bit generators, ``S64`` followed by its complement
+const S512: [u64; 8] = [
+    S64[0], S64[1], S64[2], S64[3], !S64[0], !S64[1], !S64[2], !S64[3],
+];
+
+kat! {kat_xoshiro_512_plus_plus, XoShiro512PlusPlus, 1, S512, [
+    0xf465_d748_ba28_89fa, 0x0e3d_685b_c2f1_a496, 0xf255_af89_641e_360f, 0xc846_8ed2_6308_0ba0,
+    0x9163_4a9c_ee6e_4d88, 0xaa6c_681d_54fa_c1a5, 0x74d9_aa9e_3854_2880, 0x79da_042e_d4c6_15c7,
+]}
+
+kat! {kat_xoshiro_512_super_star, XoShiro512SuperStar, 1, S512, [
+    0x6666_6666_6666_6c65, 0xd906_3360_8dba_e0aa, 0x26f9_cc9f_7245_0d4d, 0x099c_033c_09b7_4835,
+    0x6fd2_6c75_75d8_52f1, 0x7756_4880_8967_9638, 0x9c6e_0a03_1edb_5d00, 0x7a4a_93d3_f1f8_ca1f,
+]}
+
+kat! {kat_xoroshiro_128_plus, XoroShiro128Plus, 1, [S64[0], S64[1]], [
+    0xffff_ffff_ffff_ffff, 0x6789_abcd_ef01_dcb9, 0x216f_adc3_98a7_3130, 0x060b_0ba3_13a1_3c59,
+    0x4920_437d_8300_ea80, 0x2fb0_d5ba_5caf_cc5a, 0x5ff3_ca78_bcae_c9d2, 0xc4f3_590c_d058_17cc,
+]}
+
+kat! {kat_xoroshiro_128_super_star, XoroShiro128SuperStar, 2, [S64[0], S64[1]], [
+    0x9999_9999_9999_8192, 0x9999_9981_a9e6_5912, 0x8d91_f41d_e505_eb24, 0x9ae1_bfa0_fb71_fd98,
+    0x64ce_17c9_1474_0261, 0x7b55_e784_77a5_4b51, 0x2dd4_6874_d806_f11f, 0x77b8_1e8a_b0a8_3940,
+]}
+
+kat! {kat_xoroshiro_128_plus_plus, XoroShiro128PlusPlus, 2, [S64[0], S64[1]], [
+    0x0123_4567_89ab_cdee, 0xa06b_17e8_6420_2464, 0xcc97_92ef_68e5_4a58, 0xa2ae_0ceb_8a9b_12a3,
+    0xfc82_9cd5_0994_4bfc, 0x4398_963b_32ed_07cf, 0x9fb6_3a30_3f21_77aa, 0x3311_ca8c_eb44_1f1c,
+]}
+
+kat! {kat_splitmix_64, SplitMix64, 1, S64[0], [
+    0x157a_3807_a48f_aa9d, 0xd573_529b_34a1_d093, 0x2f90_b72e_996d_ccbe, 0xa2d4_1933_4c46_67ec,
+    0x0140_4ce9_1493_8008, 0x14bc_574c_2a2b_4c72, 0xb8fc_5b10_6070_8c05, 0x8931_545f_4f9e_a651,
+]}
+
+// reference vectors from Bob Jenkins' small noncryptographic PRNG, Chris Doty-Humphrey's PractRand and
+// Bernard Widynski's middle square Weyl sequence
+
+kat! {kat_jsf_large, JsfLarge, 3, S64, [
+    0xce41_2d1f_9b0d_f963, 0x98eb_dc75_aeae_4f0a, 0xb816_5e31_9446_7c60, 0x309c_8ed7_3a2f_66a4,
+    0x5824_c89f_1f7e_a495, 0x670f_c132_a6b3_0f76, 0x15a6_bf9d_c22e_7338, 0x0e6a_c0b4_5d58_caf1,
+]}
+
+kat! {kat_jsf_small, JsfSmall, 1, S32, [
+    0x8170_174e, 0x0011_c3ed, 0x9020_8e11, 0xbbce_5326, 0xbe02_faef, 0xd632_77d9, 0xdfc8_2d2a, 0x2941_bf57,
+]}
+
+kat! {kat_sfc_32, Sfc32, 3, S32, [
+    0x0123_4566, 0xf605_d972, 0x6268_c916, 0x2fae_a98a, 0xc02e_9384, 0xb12d_3464, 0xc47c_b0ed, 0x91d3_a3b9,
+]}
+
+kat! {kat_sfc_64, Sfc64, 1, S64, [
+    0x8796_a5b4_c3d2_e1ef, 0x0e69_9de2_8f5b_8fbf, 0xf2a7_3db1_24be_537c, 0xfc56_83fd_09df_4066,
+    0x9948_30c6_9930_ffaf, 0x2086_484d_bb2e_bc5b, 0xaa62_7cfc_ea44_47f8, 0xe34a_2a0a_9df7_0f7b,
+]}
+
+#[test]
+fn kat_chaotic_seeding() {
+    // the outputs after raninit of Bob Jenkins' source and the seed functions of PractRand
+    let mut jsf32 = JsfSmall::from_seed(0x0123_4567);
+    for value in [0xab31_3a0d, 0x055f_8fef, 0xf63a_00de, 0x1bda_1b36] {
+        assert_eq!(jsf32.gen(), value);
+    }
+    let mut jsf64 = JsfLarge::from_seed(0x0123_4567_89ab_cdef);
+    for value in [
+        0x4352_6f6e_3ac5_4b42,
+        0xbff8_927d_cd72_b566,
+        0x59eb_2a58_2865_46bc,
+        0x0b10_af51_9392_1ac7,
+    ] {
+        assert_eq!(jsf64.gen(), value);
+    }
+    let mut sfc32 = Sfc32::from_seed(0x0123_4567_89ab_cdef);
+    for value in [0x8471_2d97, 0xf5a3_d9c8, 0x5cd0_a295, 0x35e0_5b54] {
+        assert_eq!(sfc32.gen(), value);
+    }
+    let mut sfc64 = Sfc64::from_seed(0x0123_4567_89ab_cdef);
+    for value in [
+        0x79d7_8afb_e043_8f43,
+        0x9633_06cd_3e6e_830e,
+        0x983b_2a24_d126_ef1b,
+        0x7d89_3205_05df_8c58,
+    ] {
+        assert_eq!(sfc64.gen(), value);
+    }
+
+    // Default and From warm up the same way
+    let mut warmed = JsfLarge {
+        data: make_4_u64(PRNG {
+            generator: make_stream(7u64),
+        }),
+    };
+    for _ in 0..20 {
+        warmed.gen();
+    }
+    assert_eq!(JsfLarge::from(7u64).gen(), warmed.gen());
+}
+
+kat! {kat_middle_square, MiddleSquare, 2, [S64[0], S64[1], 0xb5ad_4ece_da1c_e2a9, 0], [
+    0x912f_eb6f, 0x7a4c_10ee, 0x9a3a_ad55, 0xd872_9c47, 0x522f_6720, 0xb5c7_01c9, 0xb552_e6e6, 0x084a_5c69,
+]}
+
+// reference vectors from wyhash final 3, the Romu paper and Lemire's testingRNG
+
+kat! {kat_wyrand, WyRand, 1, S64[0], [
+    0x58b9_6221_7aaf_c627, 0xd748_8e0f_880d_bd37, 0xa175_b746_466e_ac63, 0x159c_6469_d427_95c0,
+    0xcc2e_c308_1b14_1be7, 0xa5d3_f540_10a9_c323, 0x2832_6707_f504_a6f4, 0x5fac_ce55_11e6_122e,
+]}
+
+kat! {kat_romu_quad, RomuQuad, 1, S64, [
+    0xfedc_ba98_7654_3210, 0x6686_b7e9_1a4b_7cac, 0xaa23_a076_489b_4f17, 0x3f64_bfa5_df63_de6a,
+    0x059d_6fee_b5a8_7428, 0xad64_1703_a217_da4d, 0x1f26_5638_5c29_e3e2, 0xeef0_6ad3_9bfc_fdd7,
+]}
+
+kat! {kat_romu_trio, RomuTrio, 1, [S64[0], S64[1], S64[2]], [
+    0x0123_4567_89ab_cdef, 0x2b85_cfa9_24f4_ae28, 0x19d5_2188_07f6_2670, 0xc052_7370_0299_991a,
+    0xdbc2_2594_9206_b200, 0xfd48_a1d9_659c_a9d2, 0x817d_797d_39d2_c499, 0xa6d8_be07_45e7_fbbd,
+]}
+
+kat! {kat_romu_duo_jr, RomuDuoJr, 1, [S64[0], S64[1]], [
+    0x0123_4567_89ab_cdef, 0x7c44_7f53_146e_1ab0, 0x46e9_c60b_180d_1983, 0x4b17_b22f_0610_fcca,
+    0xa95f_f71a_f302_3ec9, 0x91b5_068a_d61c_b4c3, 0x424a_a10a_374a_8824, 0x1eb4_bf85_c829_8ffb,
+]}
+
+kat! {kat_lehmer_64, Lehmer64, 1, u128::from(S64[1]) << 64 | u128::from(S64[0]), [
+    0x8b65_1381_126e_058f, 0x1a14_9dd1_2492_78d1, 0x0daa_9060_b95b_39d8, 0x23dd_a235_6221_67ec,
+    0x33dd_1147_0b9e_0d5d, 0x1e2d_34e7_0de7_5072, 0x949f_b0bf_dfcc_d4b8, 0x0fa6_3c00_ebe5_a6c2,
+]}
+
+// reference vectors from Marsaglia's "Xorshift RNGs" and Vigna's original xorshift128+
+
+kat! {kat_xorshift_32, XorShift32, 1, S32[0], [
+    0x587d_a5a0, 0x75f3_ab44, 0x13fe_4a29, 0x8ddb_e9f4, 0xec47_2146, 0x0593_4b51, 0xf3d2_f88d, 0x34a2_d36c,
+]}
+
+kat! {kat_xorshift_64, XorShift64, 1, S64[0], [
+    0x3f28_00d6_569e_01b4, 0x606f_949a_3ceb_d0b7, 0xc69b_ba40_dddc_cad6, 0xbdc1_62a6_bf89_06c3,
+    0xaccc_fee2_b873_c40e, 0x2208_ba58_d97f_e006, 0x7942_b05e_77b9_de46, 0xf7bf_d187_e61d_fc7a,
+]}
+
+kat! {kat_xorshift_128, XorShift128, 1, [S32[3], S32[2], S32[1], S32[0]], [
+    0x6d47_49c0, 0xba54_3532, 0xa147_509a, 0x7654_3210, 0x210a_7c53, 0x3aec_20e5, 0xa1b4_64a2, 0x76a7_0636,
+]}
+
+kat! {kat_xorshift_128_plus, XorShift128Plus, 1, [S64[0], S64[1]], [
+    0x4b18_2ded_ed66_1907, 0x4b18_2e0a_46eb_a587, 0x1738_ed21_d98f_b1ac, 0xd543_68ca_3c0b_083e,
+    0x4f26_ffe8_2abc_47ca, 0x9281_65d1_d312_c746, 0x5743_ea3b_764d_f651, 0xcb30_e3f0_45ff_2491,
+]}
+
+// regression vectors, these algorithms have no published reference
+
+kat! {kat_lcg, LCG, 1, S64[0], [
+    0x0123_4567_89ab_cdef, 0x164b_fc6a_061d_c8a6, 0x6cd1_5b6a_3df0_5f81, 0x9ace_b619_6456_05f0,
+    0x3184_39f9_2e87_d1a3, 0x4724_da9f_add9_318a, 0x9606_33c2_3303_c8d5, 0x3034_70df_a6ae_ddf4,
+]}
+
+kat! {kat_splitmix_32, SplitMix32, 1, [S64[0], S64[1]], [
+    0x5a00_1532, 0x0000_0000, 0x938f_b8a7, 0x9c18_8f5c, 0xd58c_79e8, 0xbac6_63da, 0x0b36_40d9, 0x2462_c050,
+]}
+
+kat! {kat_sfc_32_small, Sfc32Small, 1, S32[0], [
+    0x98ba_1ce7, 0xf1cb_e017, 0x5ec9_17d9, 0x68d1_2bf5, 0xb3c7_b14f, 0x9ea7_d833, 0xd23c_2f5c, 0xd188_c0b9,
+]}
+
+kat! {kat_step_generator_64, StepGenerator64, 1, S64[0], [
+    0x0123_4567_89ab_cdf0, 0x0123_4567_89ab_cdf1, 0x0123_4567_89ab_cdf2, 0x0123_4567_89ab_cdf3,
+    0x0123_4567_89ab_cdf4, 0x0123_4567_89ab_cdf5, 0x0123_4567_89ab_cdf6, 0x0123_4567_89ab_cdf7,
+]}
+
+// ChaCha vectors from "ChaCha20 and Poly1305 for IETF Protocols" RFC 7539, the 32 bit block counter and 96 bit
+// nonce of the RFC are words 12 to 15 of the state, as are the 64 bit counter and 64 bit stream id here
+
+#[test]
+fn kat_chacha_20_block_function() {
+    // section 2.3.2, counter 1 and nonce 00:00:00:09:00:00:00:4a:00:00:00:00
+    let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+    let mut chacha = ChaCha20::new(key, 0x4a00_0000);
+    chacha.set_word_pos(u128::from(0x0900_0000_0000_0001u64) << 4);
+
+    let expected = [
+        0xe4e7_f110,
+        0x1559_3bd1,
+        0x1fdd_0f50,
+        0xc471_20a3,
+        0xc7f4_d1c7,
+        0x0368_c033,
+        0x9aaa_2204,
+        0x4e6c_d4c3,
+        0x4664_82d2,
+        0x09aa_9f07,
+        0x05d7_c214,
+        0xa202_8bd9,
+        0xd19c_12b5,
+        0xb94e_16de,
+        0xe883_d0cb,
+        0x4e3c_50a2,
+    ];
+    for value in expected {
+        assert_eq!(chacha.gen(), value);
+    }
+}
+
+#[test]
+fn kat_chacha_20_keystream() {
+    // appendix A.1 test vectors 1 and 2, all zero key and nonce with consecutive counters
+    let mut chacha = ChaCha20::new([0; 32], 0);
+    let expected = [
+        0xade0_b876,
+        0x903d_f1a0,
+        0xe56a_5d40,
+        0x28bd_8653,
+        0xb819_d2bd,
+        0x1aed_8da0,
+        0xccef_36a8,
+        0xc70d_778b,
+        0x7c59_41da,
+        0x8d48_5751,
+        0x3fe0_2477,
+        0x374a_d8b8,
+        0xf4b8_436a,
+        0x1ca1_1815,
+        0x69b6_87c3,
+        0x8665_eeb2,
+        0xbee7_079f,
+        0x7a38_5155,
+        0x7c97_ba98,
+        0x0d08_2d73,
+        0xa029_0fcb,
+        0x6965_e348,
+        0x3e53_c612,
+        0xed7a_ee32,
+        0x7621_b729,
+        0x434e_e69c,
+        0xb033_71d5,
+        0xd539_d874,
+        0x281f_ed31,
+        0x45fb_0a51,
+        0x1f0a_e1ac,
+        0x6f4d_794b,
+    ];
+    for value in expected {
+        assert_eq!(chacha.gen(), value);
+    }
+    assert_eq!(chacha.word_pos(), 32);
+}
+
+#[test]
+fn kat_chacha_seeking() {
+    let mut chacha = ChaCha20::new([7; 32], 0);
+    chacha.set_word_pos(u128::from(0x1_0000_0005u64) << 4 | 7);
+    chacha.set_stream(3);
+    assert_eq!(chacha.stream(), 3);
+    assert_eq!(chacha.word_pos(), u128::from(0x1_0000_0005u64) << 4 | 7);
+    assert_eq!(chacha.gen(), 0x860b_7c21);
+
+    // seeking agrees with generating in order, across a block boundary
+    let mut sequential = ChaCha8::from(5u64);
+    let outputs: [u32; 40] = core::array::from_fn(|_| sequential.gen());
+    let mut seeking = ChaCha8::from(5u64);
+    seeking.set_word_pos(13);
+    assert_eq!(seeking.gen(), outputs[13]);
+    seeking.set_word_pos(35);
+    assert_eq!(seeking.gen(), outputs[35]);
+    seeking.set_word_pos(16);
+    assert_eq!(seeking.gen(), outputs[16]);
+}
+
+// Philox and Threefry vectors from the kat_vectors file of Random123
+
+#[test]
+fn kat_philox_4x32_10() {
+    assert_eq!(
+        philox([0, 0], [0, 0, 0, 0]),
+        [0x6627_e8d5, 0xe169_c58d, 0xbc57_ac4c, 0x9b00_dbd8]
+    );
+    assert_eq!(
+        philox([u32::MAX; 2], [u32::MAX; 4]),
+        [0x408f_276d, 0x41c8_3b0e, 0xa20b_c7c6, 0x6d54_51fd]
+    );
+    assert_eq!(
+        philox(
+            [0xa409_3822, 0x299f_31d0],
+            [0x243f_6a88, 0x85a3_08d3, 0x1319_8a2e, 0x0370_7344]
+        ),
+        [0xd16c_fe09, 0x94fd_cceb, 0x5001_e420, 0x2412_6ea1]
+    );
+
+    // the generator outputs the blocks of successive counters, carrying into the higher words
+    let mut generator = Philox4x32_10::new([u32::MAX; 2]);
+    generator.seek([u32::MAX; 4]);
+    for value in [0x408f_276d, 0x41c8_3b0e, 0xa20b_c7c6, 0x6d54_51fd] {
+        assert_eq!(generator.gen(), value);
+    }
+    assert_eq!(generator.counter(), [0; 4]);
+    assert_eq!(generator.gen(), philox([u32::MAX; 2], [0; 4])[0]);
+}
+
+#[test]
+fn kat_threefry_4x64_20() {
+    assert_eq!(
+        threefry([0; 4], [0; 4]),
+        [
+            0x0921_8ebd_e6c8_5537,
+            0x5594_1f52_66d8_6105,
+            0x4bd2_5e16_2824_34dc,
+            0xee29_ec84_6bd2_e40b
+        ]
+    );
+    assert_eq!(
+        threefry([u64::MAX; 4], [u64::MAX; 4]),
+        [
+            0x29c2_4097_942b_ba1b,
+            0x0371_bbfb_0f6f_4e11,
+            0x3c23_1ffa_33f8_3a1c,
+            0xcd29_113f_de32_d168
+        ]
+    );
+
+    let mut generator = Threefry4x64_20::new([0; 4]);
+    for value in threefry([0; 4], [0; 4]) {
+        assert_eq!(generator.gen(), value);
+    }
+    assert_eq!(generator.gen(), threefry([0; 4], [1, 0, 0, 0])[0]);
+
+    generator.seek([5, 6, 7, 8]);
+    assert_eq!(generator.gen(), threefry([0; 4], [5, 6, 7, 8])[0]);
+}
+
+// squares vectors from the C source of the paper
+
+#[test]
+fn kat_squares() {
+    let key = 0xc8e4_fd15_4ce3_2f6d;
+    let mut generator = Squares32::new(key);
+    for value in [0x800c_823e, 0x5f4f_366d, 0xeee7_7e31, 0xf9a1_dcf6] {
+        assert_eq!(generator.gen(), value);
+    }
+    let mut generator = Squares64::new(key);
+    for value in [
+        0x800c_823e_cc9b_9607,
+        0x5f4f_366d_b727_a9f6,
+        0xeee7_7e31_0b90_add2,
+        0xf9a1_dcf6_ff21_60d7,
+    ] {
+        assert_eq!(generator.gen(), value);
+    }
+    assert_eq!(squares32(1 << 40, key), 0xdd22_ab39);
+    assert_eq!(squares64(1 << 40, key), 0xdd22_ab39_b358_8895);
+
+    // the key made for seed 1 must stay the same, so the stream of a seed is reproducible
+    let mut generator = Squares64::from(1u64);
+    assert_eq!(generator.key(), 0x72c3_86b1_231b_89ad);
+    generator.seek(1 << 40);
+    assert_eq!(generator.gen(), 0xb1a5_c180_2858_db00);
+    assert_eq!(generator.counter(), (1 << 40) + 1);
+    assert_eq!(Squares32::from(1u64).gen(), 0x4757_f2bd);
+}
+
+// Mersenne twister vectors from mt19937ar.out, mt19937-64.out and the C++ standard library
+
+#[test]
+fn kat_mt19937() {
+    let mut generator = Mt19937::from_array(&[0x123, 0x234, 0x345, 0x456]);
+    for value in [
+        1_067_595_299,
+        955_945_823,
+        477_289_528,
+        4_107_218_783,
+        4_228_976_476,
+    ] {
+        assert_eq!(generator.gen(), value);
+    }
+
+    let mut generator = Mt19937::new(1);
+    for value in [1_791_095_845, 4_282_876_139, 3_093_770_124, 4_005_303_368] {
+        assert_eq!(generator.gen(), value);
+    }
+
+    // the 10000th output of a default constructed std::mt19937
+    assert_eq!(Mt19937::default().nth(9999), Some(4_123_659_995));
+}
+
+#[test]
+fn kat_mt19937_64() {
+    let mut generator = Mt19937_64::from_array(&[0x12345, 0x23456, 0x34567, 0x45678]);
+    for value in [
+        7_266_447_313_870_364_031,
+        4_946_485_549_665_804_864,
+        16_945_909_448_695_747_420,
+        16_394_063_075_524_226_720,
+        4_873_882_236_456_199_058,
+    ] {
+        assert_eq!(generator.gen(), value);
+    }
+
+    let mut generator = Mt19937_64::new(1);
+    for value in [
+        2_469_588_189_546_311_528,
+        2_516_265_689_700_432_462,
+        8_323_445_853_463_659_930,
+        387_828_560_950_575_246,
+    ] {
+        assert_eq!(generator.gen(), value);
+    }
+
+    // the 10000th output of a default constructed std::mt19937_64
+    assert_eq!(
+        Mt19937_64::default().nth(9999),
+        Some(9_981_545_732_273_789_042)
+    );
+}
+
+#[test]
+fn kat_mersenne_twister_jump() {
+    // the outputs after std::mt19937::discard
+    let mut generator = Mt19937::default();
+    generator.jump(100_000);
+    assert_eq!(generator.gen(), 2_685_883_880);
+    let mut generator = Mt19937::default();
+    generator.jump(1_000_000_000);
+    assert_eq!(generator.gen(), 1_685_067_279);
+
+    let mut generator = Mt19937_64::default();
+    generator.jump(100_000);
+    assert_eq!(generator.gen(), 7_605_900_683_918_645_917);
+    let mut generator = Mt19937_64::default();
+    generator.jump(1_000_000_000);
+    assert_eq!(generator.gen(), 11_942_933_203_894_908_259);
+
+    // jumping part way through the state and by less than the state size
+    let mut stepped = Mt19937::from_array(&[1, 2, 3]);
+    let mut jumped = Mt19937::from_array(&[1, 2, 3]);
+    for _ in 0..1000 {
+        stepped.gen();
+    }
+    jumped.gen();
+    jumped.jump(999);
+    assert!(stepped.take(2000).eq(jumped.take(2000)));
+}
+
+#[test]
+fn kat_mersenne_twister_polynomials() {
+    // the precomputed characteristic polynomials are the minimal polynomials of the low output bits
+    let mut generator = Mt19937::default();
+    let sequence: Vec<bool> = (0..2 * 19937).map(|_| generator.gen() & 1 == 1).collect();
+    assert_eq!(
+        gf2::minimal_polynomial(&sequence),
+        gf2::Gf2Poly::from_words(&MT19937_POLYNOMIAL)
+    );
+
+    let mut generator = Mt19937_64::default();
+    let sequence: Vec<bool> = (0..2 * 19937).map(|_| generator.gen() & 1 == 1).collect();
+    assert_eq!(
+        gf2::minimal_polynomial(&sequence),
+        gf2::Gf2Poly::from_words(&MT19937_64_POLYNOMIAL)
+    );
+}
+
+// xoroshiro1024 vectors and the outputs after jumps from the reference sources, the jump polynomials were also
+// checked against x^(2^k) modulo the characteristic polynomials
+
+/// The state of the 1024 bit generators, ``S64`` repeated with 0, 1, 2 and 3 added
+const S1024: [u64; 16] = [
+    S64[0],
+    S64[1],
+    S64[2],
+    S64[3],
+    S64[0] + 1,
+    S64[1] + 1,
+    S64[2] + 1,
+    S64[3] + 1,
+    S64[0] + 2,
+    S64[1] + 2,
+    S64[2] + 2,
+    S64[3] + 2,
+    S64[0] + 3,
+    S64[1] + 3,
+    S64[2] + 3,
+    S64[3] + 3,
+];
+
+macro_rules! kat_xoroshiro_1024 {
+    ($fn_name:ident, $gen_type:ident, [$($expected:expr),+], jump: [$($jump:expr),+], long_jump: [$($long_jump:expr),+]) => {
+        #[test]
+        fn $fn_name() {
+            let mut generator = $gen_type { data: S1024, index: 0 };
+            for value in [$($expected),+] {
+                assert_eq!(generator.gen(), value);
+            }
+
+            // jumping from part way around the circular buffer
+            let mut generator = $gen_type { data: S1024, index: 0 };
+            for _ in 0..5 {
+                generator.gen();
+            }
+            generator.jump();
+            for value in [$($jump),+] {
+                assert_eq!(generator.gen(), value);
+            }
+            generator.long_jump();
+            for value in [$($long_jump),+] {
+                assert_eq!(generator.gen(), value);
+            }
+        }
+    };
+}
+
+kat_xoroshiro_1024! {kat_xoroshiro_1024_star, XoroShiro1024Star, [
+    0x577b_695d_f91f_7730, 0xa2ee_2226_677b_f3e8, 0x7697_cac0_0d77_04d0, 0xa884_96a2_06e0_88d0,
+    0xf5b2_e317_7869_f343, 0x4125_9bdf_e6c6_6ffb, 0x14cf_4479_8cc1_80e3, 0x46bc_105b_862b_04e3
+], jump: [0x4318_6936_265d_27ae, 0x7d8e_54f6_dcba_0e80], long_jump: [0xd63e_9f3d_ada8_49b5, 0xcb20_bbc6_aef2_f031]}
+
+kat_xoroshiro_1024! {kat_xoroshiro_1024_plus_plus, XoroShiro1024PlusPlus, [
+    0x0123_4567_89ab_cdee, 0x9e25_ad34_bb87_8f15, 0x6442_6086_2406_2442, 0xef30_3112_336b_eccd,
+    0x089b_3bb5_c123_c49c, 0x7e5e_14f2_24bf_e1ed, 0x1018_d268_0c9d_4722, 0xa2bb_330d_6f64_0750
+], jump: [0xf7a6_f6ba_7e3e_6a9f, 0x4f23_53d5_c90c_b5f2], long_jump: [0x0191_2e40_8ff9_0dc5, 0xb275_a13f_412c_ddb3]}
+
+kat_xoroshiro_1024! {kat_xoroshiro_1024_super_star, XoroShiro1024SuperStar, [
+    0x6666_6666_6666_6c65, 0x26f9_cc9f_7245_0d4d, 0xbd90_6336_08db_9ae2, 0x9999_9999_9999_9812,
+    0x6666_6666_6666_82e5, 0x26f9_cc9f_7245_23cd, 0xbd90_6336_08db_b162, 0x9999_9999_9999_ae92
+], jump: [0x559f_7aaf_31a3_6c57, 0xbf6c_e4c3_44e8_c0d8], long_jump: [0xeb2e_596f_85f0_06e8, 0x3809_7ce9_d657_4a50]}
+
+#[test]
+fn kat_xoshiro_jumps() {
+    let mut generator = XoShiro128Plus { data: S32 };
+    generator.jump();
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0xc82b_02db, 0xb5fd_2c64)
+    );
+    generator.long_jump();
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0x7617_d756, 0xe01c_79c7)
+    );
+
+    let mut generator = XoShiro128PlusPlus { data: S32 };
+    generator.jump();
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0x8490_c8a1, 0x30b5_53d6)
+    );
+    generator.long_jump();
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0xa875_937e, 0x34bb_bfe0)
+    );
+
+    let mut generator = XoShiro128SuperStar { data: S32 };
+    generator.jump();
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0x0a1f_19da, 0xd5fc_4249)
+    );
+    generator.long_jump();
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0x4c64_1438, 0x601e_07df)
+    );
+
+    let mut generator = XoShiro512PlusPlus { data: S512 };
+    generator.jump();
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0xa8c2_8bb6_d2ba_450a, 0xa914_8f59_bcd6_4fba)
+    );
+    generator.long_jump();
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0xb8b7_9429_9510_cdeb, 0xb3be_dbe5_7541_d6f8)
+    );
+
+    let mut generator = XoShiro512SuperStar { data: S512 };
+    generator.jump();
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0x8cf4_3560_3086_fb2b, 0x081d_a7e4_855a_580b)
+    );
+    generator.long_jump();
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0xac7d_ce13_87ed_9ac6, 0xb7ed_81cb_c87a_4436)
+    );
+}
+
+// multiply with carry vectors from the C sources at https://prng.di.unimi.it/ and Marsaglia's CMWC4096 post, the
+// jumps checked against stepping the C sources and big integer arithmetic in Python
+
+kat! {kat_mwc_128, Mwc128, 1, [S64[0], 1], [
+    0x0123_4567_89ab_cdef, 0xd5fc_b231_18bc_d378, 0x8e02_8fde_9650_c684, 0xef2a_bc43_8fce_056a,
+    0x1302_0259_f32e_22d3, 0x1efe_7fdb_631b_55ae, 0x5fbf_22b7_ff9a_32e8, 0x922d_dd47_9115_56be,
+]}
+
+kat! {kat_mwc_192, Mwc192, 1, [S64[0], S64[1], 1], [
+    0xfedc_ba98_7654_3210, 0xcf79_d741_4764_9e1b, 0x3208_b2dd_bccd_391f, 0x9a2b_1865_ec1b_b9e7,
+    0xb62a_a04e_a1bd_e497, 0xb3ab_bdbf_b934_9cfe, 0x7fbf_b10e_0fed_f6b5, 0xdc83_4307_382f_3d5c,
+]}
+
+kat! {kat_mwc_256, Mwc256, 1, [S64[0], S64[1], S64[2], 1], [
+    0x0f1e_2d3c_4b5a_6978, 0x253f_70e9_9a03_7017, 0xdcab_7235_01af_40f9, 0x4906_1257_f155_139f,
+    0xe7f3_b997_ad91_f92d, 0xb1a2_2196_4b83_90b8, 0xa369_4ac1_0cfe_cf01, 0xdb1b_875c_6fa8_e339,
+]}
+
+#[test]
+fn kat_mwc_jumps() {
+    let mut generator = Mwc128 { data: [S64[0], 1] };
+    generator.jump(1000);
+    assert_eq!(
+        generator.data,
+        [0x96d6_1325_1d61_bd03, 0x56fa_c713_4e36_34e6]
+    );
+
+    let mut generator = Mwc128 { data: [S64[0], 1] };
+    generator.jump((1 << 100) + 12345);
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0xfffb_88a9_16ec_2a8f, 0x6770_c4e8_5fd1_3b2d)
+    );
+
+    let mut generator = Mwc192 {
+        data: [S64[0], S64[1], 1],
+    };
+    generator.jump((1 << 100) + 12345);
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0x59ee_89f7_9f27_c825, 0x6b03_0c28_a0af_e6db)
+    );
+
+    let mut generator = Mwc256 {
+        data: [S64[0], S64[1], S64[2], 1],
+    };
+    generator.jump(u128::MAX);
+    assert_eq!(
+        (generator.gen(), generator.gen()),
+        (0x28f8_fe83_97fa_89a3, 0xe9b7_82b6_dac8_70cc)
+    );
+
+    let mut generator = Mwc256 {
+        data: [S64[0], S64[1], S64[2], 1],
+    };
+    let mut stepped = Mwc256 {
+        data: [S64[0], S64[1], S64[2], 1],
+    };
+    generator.jump(777);
+    for _ in 0..777 {
+        stepped.gen();
+    }
+    assert_eq!(generator.data, stepped.data);
+}
+
+#[test]
+fn kat_cmwc_4096() {
+    let mut data = [0; 4096];
+    for (i, word) in (0u32..).zip(&mut data) {
+        *word = i.wrapping_mul(0x9e37_79b9);
+    }
+    let mut generator = Cmwc4096 {
+        data,
+        carry: 362_436,
+        index: 4095,
+    };
+    let expected = [
+        0xfffa_783a,
+        0x15eb_5fb9,
+        0x2bd6_db7a,
+        0x41c2_573a,
+        0x57ad_d2fa,
+        0x6d99_9818,
+        0x8385_13d8,
+        0x9970_8f98,
+    ];
+    for value in expected {
+        assert_eq!(generator.gen(), value);
+    }
+    assert_eq!(generator.nth(9992), Some(0x69f8_48f0));
+    assert_eq!(<Cmwc4096 as Algorithm>::REVISION, 1);
+}
+
+// combined classic generators, vectors from Marsaglia's KISS99 post, L'Ecuyer's taus88, lfsr113 and MRG32k3a
+// sources, Marsaglia's "Xorshift RNGs" and the curand_init of cuRAND's XORWOW, with the RngStreams jumps checked
+// against powers of the transition matrices in Python
+
+kat! {kat_kiss_99, Kiss99, 1, [362_436_069, 521_288_629, 123_456_789, 380_116_160], [
+    0x2ddc_cfe0, 0x2c3a_35a8, 0x7e6e_e31a, 0xa73a_60ce, 0xbf98_47a7, 0xe03d_2a6d, 0x797a_2c20, 0x9ae5_fba6,
+]}
+
+#[test]
+fn kat_kiss_99_self_test() {
+    // the state of Marsaglia's settable(12345, 65435, 34221, 12345, ...) after the 256 calls that fill its table
+    let mut generator = Kiss99 {
+        data: [12345, 65435, 34221, 12345],
+    };
+    generator.nth(255);
+    assert_eq!(generator.nth(999_999), Some(1_372_460_312));
+}
+
+kat! {kat_taus_88, Taus88, 1, [S32[0], S32[1], S32[2]], [
+    0xdbcb_b4cb, 0xc345_7761, 0x8762_a7d1, 0xd20c_17f9, 0x7489_9a7c, 0xfd53_0154, 0x0ae5_87a9, 0x3b2d_66f4,
+]}
+
+kat! {kat_lfsr_113, Lfsr113, 1, [987_654_321; 4], [
+    0xeb97_5594, 0x471b_9434, 0x9078_435e, 0x4954_0227, 0x2ef9_f25d, 0x23c9_08d6, 0xae5e_533a, 0x6905_4221,
+]}
+
+#[test]
+fn kat_mrg_32k3a() {
+    let mut generator = Mrg32k3a::new([12345; 6]);
+    let expected = [
+        545_508_589,
+        1_368_065_410,
+        1_327_943_761,
+        3_546_985_096,
+        951_893_194,
+        2_290_915_636,
+        2_064_909_380,
+        1_527_117_980,
+    ];
+    for value in expected {
+        assert_eq!(generator.gen(), value);
+    }
+    assert_eq!(<Mrg32k3a as Algorithm>::REVISION, 1);
+
+    // the jumps start from the current substream and stream, not the current state
+    generator.next_substream();
+    assert_eq!(
+        generator.data,
+        [
+            870_504_860,
+            2_641_697_727,
+            884_013_853,
+            339_352_413,
+            2_374_306_706,
+            3_651_603_887
+        ]
+    );
+    assert_eq!(
+        [generator.gen(), generator.gen(), generator.gen()],
+        [341_016_048, 2_063_042_364, 3_686_465_802]
+    );
+    generator.next_substream();
+    assert_eq!(
+        [generator.gen(), generator.gen()],
+        [1_125_210_107, 2_302_069_253]
+    );
+
+    // the seed of the second stream of RngStreams
+    generator.next_stream();
+    assert_eq!(
+        generator.data,
+        [
+            3_692_455_944,
+            1_366_884_236,
+            2_968_912_127,
+            335_948_734,
+            4_161_675_175,
+            475_798_818
+        ]
+    );
+    assert_eq!(
+        [generator.gen(), generator.gen(), generator.gen()],
+        [3_262_379_099, 4_201_811_714, 2_942_635_747]
+    );
+    generator.next_substream();
+    assert_eq!(
+        [generator.gen(), generator.gen()],
+        [3_945_126_241, 1_993_544_544]
+    );
+}
+
+kat! {kat_xorwow, Xorwow, 1, [123_456_789, 362_436_069, 521_288_629, 88_675_123, 5_783_321, 6_615_241], [
+    0x0eb7_0507, 0xdbf1_0aa0, 0x4b5f_f98d, 0xe8db_ae01, 0x6f3b_c4a7, 0x93ef_2c63, 0x92e5_c18a, 0x5f9b_b7b7,
+]}
+
+#[test]
+fn kat_xorwow_curand() {
+    let mut generator = Xorwow::from_curand_seed(0);
+    assert_eq!(
+        [
+            generator.gen(),
+            generator.gen(),
+            generator.gen(),
+            generator.gen()
+        ],
+        [0xbd7f_03b6, 0x703e_556d, 0x845a_f0c5, 0x2830_67fd]
+    );
+    let mut generator = Xorwow::from_curand_seed(S64[0]);
+    assert_eq!(
+        [
+            generator.gen(),
+            generator.gen(),
+            generator.gen(),
+            generator.gen()
+        ],
+        [0x1a44_4210, 0x3fcc_a938, 0xac7c_5504, 0xea64_f653]
+    );
+}
@@ -19,10 +19,31 @@ extern crate alloc;
 
 // mod algorithms::jsf;
 pub mod algorithms;
+pub mod crypto;
+pub mod geometry;
+mod gf2;
+#[cfg(test)]
+mod kat;
+mod math;
+pub mod multivariate;
+pub mod registry;
+mod reservoir;
+#[cfg(feature = "std")]
+mod rotation;
+pub mod seq;
 pub mod smol_core;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod weighted;
 
 pub use algorithms::*;
+pub use crypto::*;
+pub use geometry::*;
+pub use multivariate::*;
+pub use registry::{AlgorithmInfo, DynAlgorithm};
+pub use seq::*;
 pub use smol_core::*;
+pub use weighted::*;
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -115,17 +136,49 @@ mod tests {
     gen_init_test! {test_step_generator_64, StepGenerator64, false}
     gen_init_test! {test_step_generator_128, StepGenerator128, false}
 
+    gen_init_test! {test_chacha_8, ChaCha8, false}
+    gen_init_test! {test_chacha_12, ChaCha12, false}
+    gen_init_test! {test_chacha_20, ChaCha20, false}
+
     gen_init_test! {test_jsf_large, JsfLarge, true}
+    gen_init_test! {test_jsf_small, JsfSmall, false}
     gen_init_test! {test_middle_square, MiddleSquare, false}
     gen_init_test! {test_lcg, LCG, false}
+    gen_init_test! {test_lehmer_64, Lehmer64, false}
+
+    gen_init_test! {test_wyrand, WyRand, false}
+    gen_init_test! {test_romu_quad, RomuQuad, false}
+    gen_init_test! {test_romu_trio, RomuTrio, false}
+    gen_init_test! {test_romu_duo_jr, RomuDuoJr, false}
+
+    gen_init_test! {test_mt19937, Mt19937, false}
+    gen_init_test! {test_mt19937_64, Mt19937_64, false}
+
+    gen_init_test! {test_mwc_128, Mwc128, false}
+    gen_init_test! {test_mwc_192, Mwc192, false}
+    gen_init_test! {test_mwc_256, Mwc256, false}
+    gen_init_test! {test_cmwc_4096, Cmwc4096, false}
+
+    gen_init_test! {test_kiss_99, Kiss99, false}
+    gen_init_test! {test_taus_88, Taus88, false}
+    gen_init_test! {test_lfsr_113, Lfsr113, false}
+    gen_init_test! {test_mrg_32k3a, Mrg32k3a, false}
+
+    gen_init_test! {test_philox, Philox4x32_10, false}
+    gen_init_test! {test_threefry, Threefry4x64_20, false}
+
+    gen_init_test! {test_squares_32, Squares32, false}
+    gen_init_test! {test_squares_64, Squares64, false}
 
     gen_init_test! {test_scf_32, Sfc32, false}
     gen_init_test! {test_scf_32_small, Sfc32Small, false}
+    gen_init_test! {test_scf_64, Sfc64, false}
 
     gen_init_test! {test_xor32, XorShift32, false}
     gen_init_test! {test_xor64, XorShift64, false}
     gen_init_test! {test_xor128, XorShift128, false}
     gen_init_test! {test_xor128_plus, XorShift128Plus, false}
+    gen_init_test! {test_xorwow, Xorwow, false}
 
     gen_init_test! {test_xoshiro_256_super_star, XoShiro256SuperStar, false}
 
@@ -135,7 +188,592 @@ mod tests {
     gen_init_test! {test_xoshiro_256_plus, XoShiro256Plus, false}
     gen_init_test! {test_xoshiro_256_plus_plus, XoShiro256PlusPlus, false}
 
+    gen_init_test! {test_xoshiro_128_plus_plus, XoShiro128PlusPlus, false}
+    gen_init_test! {test_xoshiro_128_plus, XoShiro128Plus, false}
+    gen_init_test! {test_xoshiro_128_super_star, XoShiro128SuperStar, false}
+    gen_init_test! {test_xoshiro_512_plus_plus, XoShiro512PlusPlus, false}
+    gen_init_test! {test_xoshiro_512_super_star, XoShiro512SuperStar, false}
+
     gen_init_test! {test_xoroshiro_256_plus_plus, XoroShiro128PlusPlus, false}
     gen_init_test! {test_xoroshiro_256_plus, XoroShiro128Plus, false}
     gen_init_test! {test_xorpshiro_256_super_star, XoroShiro128SuperStar, false}
+    gen_init_test! {test_xoroshiro_1024_plus_plus, XoroShiro1024PlusPlus, false}
+    gen_init_test! {test_xoroshiro_1024_star, XoroShiro1024Star, false}
+    gen_init_test! {test_xoroshiro_1024_super_star, XoroShiro1024SuperStar, false}
+
+    #[test]
+    fn test_dynamic_weighted_index() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+        let mut index = DynamicWeightedIndex::new(&[]);
+        assert!(index.sample(&mut prng).is_none());
+
+        for w in [1.0, 0.0, 3.0, 0.0, 2.0] {
+            index.push(w);
+        }
+        assert_eq!(index.len(), 5);
+        assert!((index.total() - 6.0).abs() < 1e-12);
+
+        let mut counts = [0u32; 5];
+        for _ in 0..6000 {
+            counts[index.sample(&mut prng).unwrap()] += 1;
+        }
+        assert_eq!(counts[1], 0);
+        assert_eq!(counts[3], 0);
+        assert!(counts[2] > counts[4] && counts[4] > counts[0]);
+
+        index.update(2, 0.0);
+        index.update(4, 0.0);
+        for _ in 0..100 {
+            assert_eq!(index.sample(&mut prng), Some(0));
+        }
+    }
+
+    #[test]
+    fn test_math_fallbacks() {
+        // without the std feature these are the portable versions, checked against the std methods
+        let ulps = |a: f64, b: f64| a.to_bits().abs_diff(b.to_bits());
+        let mut prng = PRNG {
+            generator: SplitMix64::default(),
+        };
+        for _ in 0..100_000 {
+            let x = f64::from_bits(prng.gen_u64() >> 1);
+            if x.is_finite() {
+                assert!(ulps(math::sqrt(x), x.sqrt()) <= 1, "sqrt({x:e})");
+                assert!(ulps(math::ln(x), x.ln()) <= 2, "ln({x:e})");
+            }
+        }
+        for _ in 0..100_000 {
+            let x = prng.gen_f64() * 1454.0 - 745.0;
+            assert!(ulps(math::exp(x), x.exp()) <= 2, "exp({x:e})");
+            let x = -prng.gen_f64();
+            assert!(ulps(math::ln_1p(x), x.ln_1p()) <= 3, "ln_1p({x:e})");
+            let x = f64::from_bits(prng.gen_u64() >> 2);
+            assert!(ulps(math::ln_1p(x), x.ln_1p()) <= 3, "ln_1p({x:e})");
+        }
+        let (above, below, subnormal) = (1.0 + 1e-9, 1.0 - 1e-9, f64::MIN_POSITIVE / 3.0);
+        for x in [1.0, above, below, 0.5, 1.414, 2.0, 1e-300, subnormal] {
+            assert!(ulps(math::sqrt(x), x.sqrt()) <= 1, "sqrt({x:e})");
+            assert!(ulps(math::ln(x), x.ln()) <= 2, "ln({x:e})");
+            assert!(ulps(math::exp(-x), (-x).exp()) <= 2, "exp({:e})", -x);
+        }
+        assert_eq!(ulps(math::exp(710.0), f64::INFINITY), 0);
+        assert_eq!(ulps(math::exp(-746.0), 0.0), 0);
+        assert_eq!(ulps(math::ln(0.0), f64::NEG_INFINITY), 0);
+        assert_eq!(ulps(math::sqrt(f64::INFINITY), f64::INFINITY), 0);
+        assert!(math::ln(-1.0).is_nan() && math::sqrt(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_multivariate_normal() {
+        let l = cholesky(&[4.0, 2.0, 2.0, 5.0], 2).unwrap();
+        assert_eq!(l, [2.0, 0.0, 1.0, 2.0]);
+
+        assert_eq!(
+            MultivariateNormal::new(&[0.0, 0.0], &[1.0, 2.0, 2.0, 1.0], 2).err(),
+            Some(MultivariateError::NotPositiveDefinite)
+        );
+        assert_eq!(
+            MultivariateNormal::new(&[0.0], &[1.0, 0.0, 0.0, 1.0], 2).err(),
+            Some(MultivariateError::DimensionMismatch)
+        );
+
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+        let mvn = MultivariateNormal::new(&[1.0, -2.0], &[4.0, 2.0, 2.0, 5.0], 2).unwrap();
+        let n = 20000;
+        let mut x = [0f64; 2];
+        let (mut m0, mut m1, mut c01) = (0f64, 0f64, 0f64);
+        for _ in 0..n {
+            mvn.sample(&mut prng, &mut x);
+            m0 += x[0];
+            m1 += x[1];
+            c01 += (x[0] - 1.0) * (x[1] + 2.0);
+        }
+        let n = f64::from(n);
+        assert!((m0 / n - 1.0).abs() < 0.1);
+        assert!((m1 / n + 2.0).abs() < 0.1);
+        assert!((c01 / n - 2.0).abs() < 0.2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_dirichlet() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+        assert!(Dirichlet::new(&[1.0]).is_err());
+        assert!(Dirichlet::new(&[1.0, 0.0]).is_err());
+
+        for alphas in [[0.5, 1.0, 3.0], [1e-3, 1e-3, 1e-3]] {
+            let dirichlet = Dirichlet::new(&alphas).unwrap();
+            let mut x = [0f64; 3];
+            for _ in 0..1000 {
+                dirichlet.sample(&mut prng, &mut x);
+                assert!(x.iter().all(|v| v.is_finite() && *v >= 0.0));
+                assert!((x.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+            }
+        }
+
+        // subtracting these from their sum leaves a negative rest, which handed the whole stick to the last alpha
+        let dirichlet = Dirichlet::new(&[0.01, 0.02, 0.03, 1e-18]).unwrap();
+        let mut x = [0f64; 4];
+        for _ in 0..1000 {
+            dirichlet.sample(&mut prng, &mut x);
+            assert!(x.iter().all(|v| v.is_finite() && *v >= 0.0));
+            assert!(x[3] < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_multinomial() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+        let mut counts = [0u64; 4];
+        Multinomial::sample(&mut prng, 10000, &[1.0, 0.0, 2.0, 1.0], &mut counts);
+        assert_eq!(counts.iter().sum::<u64>(), 10000);
+        assert_eq!(counts[1], 0);
+        assert!(counts[2] > 4500 && counts[2] < 5500);
+
+        // a generator stuck at the top of [0,1) fails every trial with p < 1, so the last non-zero category must be
+        // conditioned on exactly one or the draws leak into the zero probability category after it
+        struct Stuck;
+        impl Algorithm for Stuck {
+            type Output = u64;
+            fn gen(&mut self) -> u64 {
+                u64::MAX
+            }
+        }
+        let mut prng = PRNG { generator: Stuck };
+        Multinomial::sample(&mut prng, 100, &[0.1, 0.2, 0.3, 0.0], &mut counts);
+        assert_eq!(counts, [0, 0, 100, 0]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_geometry_samplers() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+        let norm = |x: &[f64]| x.iter().map(|v| v * v).sum::<f64>().sqrt();
+
+        for _ in 0..1000 {
+            assert!((norm(&UnitCircle.sample(&mut prng)) - 1.0).abs() < 1e-12);
+            assert!((norm(&UnitSphere.sample_array::<_, 3>(&mut prng)) - 1.0).abs() < 1e-12);
+            assert!((norm(&UnitSphere.sample_array::<_, 7>(&mut prng)) - 1.0).abs() < 1e-12);
+            assert!(norm(&UnitBall.sample_array::<_, 2>(&mut prng)) <= 1.0);
+            assert!(norm(&UnitBall.sample_array::<_, 12>(&mut prng)) <= 1.0);
+
+            let simplex = UnitSimplex.sample_array::<_, 4>(&mut prng);
+            assert!(simplex.iter().all(|x| *x >= 0.0));
+            assert!((simplex.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+
+            let r = norm(&Annulus::new(1.0, 2.0).sample(&mut prng));
+            assert!((1.0..=2.0 + 1e-12).contains(&r));
+        }
+    }
+
+    #[test]
+    fn test_polygon() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+        assert!(Polygon::new(&[[0.0, 0.0], [1.0, 1.0]]).is_none());
+        assert!(Polygon::new(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]).is_none());
+
+        // a pentagram, the vertices of a regular pentagon taken two apart
+        let pentagram: Vec<[f64; 2]> = [0.0, 2.0, 4.0, 1.0, 3.0]
+            .iter()
+            .map(|k| {
+                let angle = k * core::f64::consts::TAU / 5.0;
+                [angle.cos(), angle.sin()]
+            })
+            .collect();
+        assert!(Polygon::new(&pentagram).is_none());
+
+        // clockwise L shape, area 3
+        let l_shape = Polygon::new(&[
+            [0.0, 0.0],
+            [0.0, 2.0],
+            [1.0, 2.0],
+            [1.0, 1.0],
+            [2.0, 1.0],
+            [2.0, 0.0],
+        ])
+        .unwrap();
+        assert!((l_shape.area() - 3.0).abs() < 1e-12);
+
+        let mut upper = 0;
+        for _ in 0..3000 {
+            let [x, y] = l_shape.sample(&mut prng);
+            assert!((0.0..=2.0).contains(&x) && (0.0..=2.0).contains(&y));
+            assert!(x <= 1.0 || y <= 1.0);
+            if y > 1.0 {
+                upper += 1;
+            }
+        }
+        assert!(upper > 850 && upper < 1150);
+
+        // a square with extra vertices along its edges, one of them repeated
+        let square = Polygon::new(&[
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 1.0],
+            [2.0, 2.0],
+            [1.0, 2.0],
+            [1.0, 2.0],
+            [0.0, 2.0],
+            [0.0, 1.0],
+        ])
+        .unwrap();
+        assert!((square.area() - 4.0).abs() < 1e-12);
+
+        // a constant coordinate is kept exactly
+        let triangle = Triangle::new([0.0, 0.0, 1.0], [1.0, 0.0, 1.0], [0.0, 1.0, 1.0]);
+        let [x, y, z] = triangle.sample(&mut prng);
+        assert!(x + y <= 1.0 && z.to_bits() == 1f64.to_bits());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_random_rotations() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+
+        let q = prng.random_quaternion();
+        assert!((q.iter().map(|v| v * v).sum::<f64>() - 1.0).abs() < 1e-12);
+
+        let r = prng.random_rotation_3d();
+        let det = r[0][0] * (r[1][1] * r[2][2] - r[1][2] * r[2][1])
+            - r[0][1] * (r[1][0] * r[2][2] - r[1][2] * r[2][0])
+            + r[0][2] * (r[1][0] * r[2][1] - r[1][1] * r[2][0]);
+        assert!((det - 1.0).abs() < 1e-12);
+
+        let n = 6;
+        let mut q = [0f64; 36];
+        prng.random_orthogonal(n, &mut q);
+        for a in 0..n {
+            for b in 0..n {
+                let dot: f64 = (0..n).map(|i| q[i * n + a] * q[i * n + b]).sum();
+                let expected = if a == b { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[no_alloc(forbid)]
+    #[test]
+    fn test_slice_sampling() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+
+        let mut counts = [0u32; 7];
+        for _ in 0..7000 {
+            counts[prng.gen_u64_in_range(7) as usize] += 1;
+        }
+        assert!(counts.iter().all(|c| *c > 850 && *c < 1150));
+
+        let mut values = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        prng.shuffle(&mut values);
+        let mut sorted = values;
+        sorted.sort_unstable();
+        assert_eq!(sorted, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let (chosen, rest) = prng.partial_shuffle(&mut values, 3);
+        assert_eq!((chosen.len(), rest.len()), (3, 7));
+
+        let empty: [u8; 0] = [];
+        assert!(prng.choose(&empty).is_none());
+        assert!(values.contains(prng.choose(&values).unwrap()));
+        *prng.choose_mut(&mut values).unwrap() = 100;
+        assert!(values.contains(&100));
+
+        let sample = prng.choose_multiple(&sorted, 4);
+        assert_eq!(sample.len(), 4);
+        let mut last = None;
+        for v in sample {
+            assert!(last < Some(v));
+            last = Some(v);
+        }
+        assert_eq!(prng.choose_multiple(&sorted, 20).count(), 10);
+    }
+
+    #[test]
+    fn test_reservoir_sampling() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+
+        assert_eq!(prng.sample_iter_reservoir(0..3, 5).len(), 3);
+        assert!(prng.sample_iter_reservoir(0..3, 0).is_empty());
+
+        let mut counts = [0u32; 20];
+        for _ in 0..2000 {
+            let mut sample = prng.sample_iter_reservoir(0..20, 5);
+            sample.sort_unstable();
+            sample.dedup();
+            assert_eq!(sample.len(), 5);
+            for i in sample {
+                counts[i] += 1;
+            }
+        }
+        assert!(counts.iter().all(|c| *c > 400 && *c < 600));
+
+        let weights = [(0, 1.0), (1, 0.0), (2, 8.0), (3, f64::NAN), (4, 1.0)];
+        let mut counts = [0u32; 5];
+        for _ in 0..2000 {
+            let sample = prng.sample_iter_weighted_reservoir(weights, 2);
+            assert_eq!(sample.len(), 2);
+            for i in sample {
+                counts[i] += 1;
+            }
+        }
+        assert_eq!(counts[1] + counts[3], 0);
+        assert!(counts[2] > 1900);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_choose_weighted_multiple() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+
+        let weights = [1.0, 0.0, 4.0, -1.0, 2.0, 1.0];
+        assert_eq!(prng.choose_weighted_multiple(&weights, 10).len(), 4);
+
+        let mut first = [0u32; 6];
+        for _ in 0..4000 {
+            let chosen = prng.choose_weighted_multiple(&weights, 3);
+            assert_eq!(chosen.len(), 3);
+            assert!(chosen.iter().all(|i| weights[*i] > 0.0));
+            first[chosen[0]] += 1;
+        }
+        // P(first = 2) = 4 / 8
+        assert!(first[2] > 1850 && first[2] < 2150);
+
+        let many: Vec<f64> = (0..1000)
+            .map(|i| if i % 2 == 0 { 1.0 } else { 0.0 })
+            .collect();
+        let chosen = prng.choose_weighted_multiple(&many, 10);
+        assert_eq!(chosen.len(), 10);
+        assert!(chosen.iter().all(|i| i % 2 == 0));
+    }
+
+    #[test]
+    fn test_index_generators() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+
+        let mut permutation = prng.random_permutation(50);
+        permutation.sort_unstable();
+        assert!(permutation.iter().enumerate().all(|(i, p)| i == *p));
+
+        let combination = prng.random_combination(100, 10);
+        assert_eq!(combination.len(), 10);
+        assert!(combination.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(prng.random_combination(5, 5), [0, 1, 2, 3, 4]);
+
+        for n in [0, 2, 3, 10] {
+            let derangement = prng.random_derangement(n);
+            assert!(derangement.iter().enumerate().all(|(i, p)| i != *p));
+        }
+
+        for (n, k) in [(10, 8), (1000, 5), (1000, 100), (0, 0)] {
+            let mut sample = prng.index_sample(n, k);
+            sample.sort_unstable();
+            sample.dedup();
+            assert_eq!(sample.len(), k);
+            assert!(sample.iter().all(|i| *i < n));
+        }
+
+        // the 2 derangements of 0..3 are equally likely
+        let mut cyclic = 0;
+        for _ in 0..2000 {
+            if prng.random_derangement(3) == [1, 2, 0] {
+                cyclic += 1;
+            }
+        }
+        assert!(cyclic > 900 && cyclic < 1100);
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_statistical_battery() {
+        let mut prng = PRNG {
+            generator: XoShiro256Plus::default(),
+        };
+        for result in testing::run_battery(&mut prng) {
+            assert!(result.passed(1e-4), "{} failed", result.name);
+        }
+
+        let mut prng = PRNG {
+            generator: StepGenerator64::default(),
+        };
+        let failures = testing::run_battery(&mut prng)
+            .iter()
+            .filter(|result| !result.passed(1e-4))
+            .count();
+        assert!(failures >= 4);
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_serial() {
+        // the example of NIST SP 800-22 section 2.11.4, the sequence 0011011101 with m = 3
+        struct Example;
+        impl Algorithm for Example {
+            type Output = u16;
+            fn gen(&mut self) -> u16 {
+                0b0011_0111_0100_0000
+            }
+        }
+        let mut prng = PRNG { generator: Example };
+        let [first, second] = testing::serial(&mut prng, 10, 3);
+        assert!((first.p_value - 0.808_792).abs() < 1e-6);
+        assert!((second.p_value - 0.670_320).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_linear_complexity() {
+        // the example of NIST SP 800-22 section 2.10.4, the sequence 1101011110001 has linear complexity 4
+        let example = [1, 1, 0, 1, 0, 1, 1, 1, 1, 0, 0, 0, 1].map(|bit| bit == 1);
+        assert_eq!(gf2::minimal_polynomial(&example).degree(), Some(4));
+        // a sequence of zeros is generated by the empty LFSR, and a single one at the end needs the full length
+        assert_eq!(gf2::minimal_polynomial(&[false; 8]).degree(), Some(0));
+        let mut last = [false; 8];
+        last[7] = true;
+        assert_eq!(gf2::minimal_polynomial(&last).degree(), Some(8));
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_battery_catches_broken_xoroshiro() {
+        // revision 1 of XoroShiro128SuperStar, whose update never mixed s0 into s1
+        struct BrokenXoroShiro {
+            data: [u64; 2],
+        }
+        impl Algorithm for BrokenXoroShiro {
+            type Output = u64;
+            fn gen(&mut self) -> u64 {
+                let [s0, s1] = self.data;
+                let result = s0.wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+                self.data = [s0.rotate_left(24) ^ s1 ^ (s1 << 16), s1.rotate_left(37)];
+                result
+            }
+        }
+
+        let mut prng = PRNG {
+            generator: BrokenXoroShiro {
+                data: XoroShiro128SuperStar::default().data,
+            },
+        };
+        let failures = testing::run_battery(&mut prng)
+            .iter()
+            .filter(|result| !result.passed(1e-4))
+            .count();
+        assert!(failures >= 4);
+    }
+
+    #[test]
+    fn test_registry() {
+        for info in registry::list_algorithms() {
+            let mut generator = registry::from_name(info.name, Some(7)).unwrap();
+            assert_eq!(generator.as_mut().output_bits() as usize, info.output_bits);
+            assert!(registry::from_name(info.type_name, None).is_some());
+        }
+        assert!(registry::from_name("XOSHIRO256++", None).is_some());
+        assert!(registry::from_name("mersenne", None).is_none());
+
+        // the state excludes the stream starts, output buffers and buffer indices
+        let state_bits = |name| registry::find(name).unwrap().state_bits;
+        assert_eq!(state_bits("mrg32k3a"), 192);
+        assert_eq!(state_bits("mt19937"), 624 * 32);
+        assert_eq!(state_bits("chacha20"), 256 + 128);
+        assert_eq!(state_bits("philox4x32-10"), 64 + 128);
+        assert_eq!(state_bits("cmwc4096"), 4097 * 32);
+        assert_eq!(state_bits("xoroshiro1024**"), 1024);
+        assert_eq!(state_bits("xoshiro256++"), 256);
+
+        // the dynamic generator matches the static one
+        let mut expected = XoShiro128PlusPlus::from(42u64);
+        let mut prng = PRNG {
+            generator: registry::from_name("xoshiro128++", Some(42)).unwrap(),
+        };
+        let high = u64::from(expected.gen());
+        assert_eq!(prng.gen_u64(), (high << 32) | u64::from(expected.gen()));
+
+        let mut generator = registry::from_name("xoshiro128++", Some(42)).unwrap();
+        let mut bytes = [0u8; 6];
+        generator.as_mut().fill_bytes(&mut bytes);
+        let mut expected = XoShiro128PlusPlus::from(42u64);
+        assert_eq!(bytes[..4], expected.gen().to_le_bytes());
+        assert_eq!(bytes[4..], expected.gen().to_le_bytes()[..2]);
+    }
+
+    #[test]
+    fn test_poisson() {
+        let mut prng = PRNG {
+            generator: JsfLarge::default(),
+        };
+
+        for lambda in [0.0, 0.3, 1.0, 4.5, 10.0, 37.2] {
+            let n = 20_000;
+            let sum: u64 = (0..n).map(|_| prng.poisson(lambda)).sum();
+            #[allow(clippy::cast_precision_loss)]
+            let mean = sum as f64 / f64::from(n);
+            assert!((mean - lambda).abs() <= 5.0 * (lambda / f64::from(n)).sqrt());
+        }
+    }
+
+    #[test]
+    fn test_crypto() {
+        let mut prng = PRNG {
+            generator: SecureChaCha20::new([1; 32], 0),
+        };
+
+        // the token is the keystream, an odd length truncates the last word
+        let mut keystream = ChaCha20::new([1; 32], 0);
+        let mut expected = [0u8; 12];
+        keystream.fill_bytes(&mut expected);
+        let token: [u8; 11] = prng.gen_token_bytes();
+        assert_eq!(token, expected[..11]);
+
+        let password = prng.gen_password(64, "ab");
+        assert_eq!(password.len(), 64);
+        assert!(password.contains('a') && password.contains('b'));
+        assert!(password.chars().all(|c| c == 'a' || c == 'b'));
+        let graphic = prng.gen_password(16, ASCII_GRAPHIC);
+        assert!(graphic.chars().all(|c| c.is_ascii_graphic()));
+
+        let uuid = prng.gen_uuid_v4_secure();
+        assert_eq!(uuid[6] >> 4, 4);
+        assert_eq!(uuid[8] >> 6, 0b10);
+        let example = 0x123e_4567_e89b_42d3_a456_4266_1417_4000_u128.to_be_bytes();
+        assert_eq!(
+            uuid_to_string(&example),
+            "123e4567-e89b-42d3-a456-426614174000"
+        );
+    }
+
+    #[test]
+    fn test_squares_keys() {
+        assert!(is_valid_squares_key(0xc8e4_fd15_4ce3_2f6d));
+        // a zero digit, a repeated digit within a half, and the same digit either side of the halves
+        assert!(!is_valid_squares_key(0xc8e4_fd15_4ce3_2f60));
+        assert!(!is_valid_squares_key(0x548c_9dec_bce6_5297));
+        assert!(!is_valid_squares_key(0xc8e4_fd15_5ce3_2f6d));
+
+        for seed in 0..1000u64 {
+            assert!(is_valid_squares_key(Squares32::from(seed).key()));
+        }
+        assert!(is_valid_squares_key(Squares64::default().key()));
+    }
 }
@@ -0,0 +1,147 @@
+//! The floating point functions that ``core`` does not provide, used by the samplers that work without ``std``
+//!
+//! With the ``std`` feature these are the ``std`` methods, so enabling it does not change any sample. Without it
+//! they are computed from the bits of the float, within a couple of ulps of ``std`` and with the same handling of
+//! zero, negative, infinite and NaN arguments.
+
+/// Square root, from ``std`` when it is available
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+/// Natural logarithm, from ``std`` when it is available
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+/// Exponential, from ``std`` when it is available
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn exp(x: f64) -> f64 {
+    x.exp()
+}
+
+/// ``ln(1 + x)``, accurate for small ``x``, from ``std`` when it is available
+#[cfg(feature = "std")]
+#[inline(always)]
+pub fn ln_1p(x: f64) -> f64 {
+    x.ln_1p()
+}
+
+/// The high part of ``ln(2)``, with trailing zeros so that its product with an exponent is exact
+#[cfg(not(feature = "std"))]
+const LN2_HI: f64 = f64::from_bits(0x3fe6_2e42_fee0_0000);
+
+/// The rest of ``ln(2)``
+#[cfg(not(feature = "std"))]
+const LN2_LO: f64 = f64::from_bits(0x3dea_39ef_3579_3c76);
+
+/// Square root by Newton's method, from an initial guess that halves the exponent
+#[cfg(not(feature = "std"))]
+pub fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x < 0f64 {
+        return f64::NAN;
+    }
+    if x == 0f64 || x == f64::INFINITY {
+        return x;
+    }
+    if x < f64::MIN_POSITIVE {
+        // subnormals are scaled up by 2^104 so the guess is good, and the root scaled down by 2^52
+        return sqrt(x * f64::from_bits(0x4670_0000_0000_0000))
+            * f64::from_bits(0x3cb0_0000_0000_0000);
+    }
+
+    // within 7% of the root, so five quadratically converging steps reach full precision
+    let mut y = f64::from_bits((x.to_bits() >> 1) + 0x1ff8_0000_0000_0000);
+    for _ in 0..5 {
+        y = 0.5 * (y + x / y);
+    }
+    y
+}
+
+/// Natural logarithm, ``ln(m 2^e) = e ln(2) + 2 atanh((m - 1) / (m + 1))`` with ``m`` in ``[sqrt(1/2), sqrt(2))``
+#[cfg(not(feature = "std"))]
+pub fn ln(x: f64) -> f64 {
+    if x.is_nan() || x < 0f64 {
+        return f64::NAN;
+    }
+    if x == 0f64 {
+        return f64::NEG_INFINITY;
+    }
+    if x == f64::INFINITY {
+        return x;
+    }
+    if x < f64::MIN_POSITIVE {
+        // subnormals are scaled up by 2^54 to get a normal mantissa
+        return ln(x * f64::from_bits(0x4350_0000_0000_0000)) - 54f64 * core::f64::consts::LN_2;
+    }
+
+    let bits = x.to_bits();
+    let mut exponent = ((bits >> 52) as i32) - 1023;
+    let mut mantissa = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+    if mantissa > core::f64::consts::SQRT_2 {
+        mantissa *= 0.5;
+        exponent += 1;
+    }
+
+    let series = atanh_series((mantissa - 1f64) / (mantissa + 1f64));
+    f64::from(exponent) * LN2_HI + (f64::from(exponent) * LN2_LO + series)
+}
+
+/// ``2 atanh(s) = ln((1 + s) / (1 - s))`` for ``|s| <= 0.2``, where the odd series has converged to double
+/// precision by ``s^27``
+#[cfg(not(feature = "std"))]
+fn atanh_series(s: f64) -> f64 {
+    let s2 = s * s;
+    let mut series = 0f64;
+    for k in (0..14).rev() {
+        series = series * s2 + 1f64 / f64::from(2 * k + 1);
+    }
+    2f64 * s * series
+}
+
+/// Exponential, ``exp(k ln(2) + r) = 2^k exp(r)`` with ``|r| <= ln(2) / 2``
+#[cfg(not(feature = "std"))]
+pub fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.8 {
+        return f64::INFINITY;
+    }
+    if x < -745.2 {
+        return 0f64;
+    }
+
+    let half = if x < 0f64 { -0.5 } else { 0.5 };
+    let k = (x * core::f64::consts::LOG2_E + half) as i32;
+    let r = (x - f64::from(k) * LN2_HI) - f64::from(k) * LN2_LO;
+
+    // |r| < 0.347, so the series has converged to double precision by r^13 / 13!
+    let mut series = 1f64;
+    for n in (1..14).rev() {
+        series = 1f64 + series * r / f64::from(n);
+    }
+
+    // 2^k is applied in two halves, as k runs past the exponent range of a single power
+    let scale = |j: i32| f64::from_bits(u64::from((1023 + j).unsigned_abs()) << 52);
+    series * scale(k / 2) * scale(k - k / 2)
+}
+
+/// ``ln(1 + x)``, from the series of ``2 atanh(x / (2 + x))`` near zero where ``1 + x`` would round
+#[cfg(not(feature = "std"))]
+pub fn ln_1p(x: f64) -> f64 {
+    if (-0.33..=0.5).contains(&x) {
+        return atanh_series(x / (2f64 + x));
+    }
+    // the rounding of 1 + x is corrected to first order, u is never 1 outside of the series range
+    let u = 1f64 + x;
+    if u == f64::INFINITY {
+        return u;
+    }
+    ln(u) * x / (u - 1f64)
+}
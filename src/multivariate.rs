@@ -0,0 +1,309 @@
+//! This module contains the multivariate distributions, these sample into caller provided buffers
+//!
+//! ``cholesky`` and ``MultivariateNormal`` only need ``alloc``, so they are available without the ``std`` feature
+//! for embedded use, while ``Dirichlet`` builds on ``gamma`` and needs ``std``.
+
+use crate::math;
+use crate::smol_core::{Algorithm, PRNG};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The errors that can occur when constructing a multivariate distribution
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultivariateError {
+    /// The length of a parameter slice does not match the dimension of the distribution
+    DimensionMismatch,
+    /// The covariance matrix is not symmetric positive-definite
+    NotPositiveDefinite,
+    /// A parameter is outside of the support of the distribution
+    InvalidParameter,
+}
+
+impl fmt::Display for MultivariateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MultivariateError::DimensionMismatch => {
+                write!(f, "parameter length does not match the dimension")
+            }
+            MultivariateError::NotPositiveDefinite => {
+                write!(f, "covariance matrix is not positive-definite")
+            }
+            MultivariateError::InvalidParameter => write!(f, "parameter is out of range"),
+        }
+    }
+}
+
+/// Computes the lower triangular Cholesky factor L of a row major symmetric matrix, such that A = L L^T
+///
+/// Only the lower triangle of `a` is read. The strict upper triangle of the result is zero.
+///
+/// # Errors
+/// Returns an error if `a` is not ``dim`` by ``dim`` or if the matrix is not positive-definite
+pub fn cholesky(a: &[f64], dim: usize) -> Result<Vec<f64>, MultivariateError> {
+    if a.len() != dim * dim {
+        return Err(MultivariateError::DimensionMismatch);
+    }
+
+    let mut l = alloc::vec![0f64; dim * dim];
+
+    for i in 0..dim {
+        for j in 0..=i {
+            let dot: f64 = (0..j).map(|k| l[i * dim + k] * l[j * dim + k]).sum();
+            let value = a[i * dim + j] - dot;
+
+            if i == j {
+                if value.is_nan() || value <= 0f64 {
+                    return Err(MultivariateError::NotPositiveDefinite);
+                }
+                l[i * dim + i] = math::sqrt(value);
+            } else {
+                l[i * dim + j] = value / l[j * dim + j];
+            }
+        }
+    }
+
+    Ok(l)
+}
+
+/// Multivariate normal distribution N(mu, Sigma)
+///
+/// The covariance matrix is factored once on construction, each sample then costs ``dim`` normal samples and a
+/// triangular matrix vector product.
+///
+/// ```rust
+/// use smolprng::{JsfLarge, MultivariateNormal, PRNG};
+/// let mut prng = PRNG{generator: JsfLarge::default()};
+///
+/// let mean = [1.0, -1.0];
+/// let cov = [2.0, 0.5,
+///            0.5, 1.0];
+/// let mvn = MultivariateNormal::new(&mean, &cov, 2).unwrap();
+///
+/// let mut x = [0f64; 2];
+/// mvn.sample(&mut prng, &mut x);
+/// ```
+pub struct MultivariateNormal {
+    mean: Vec<f64>,
+    factor: Vec<f64>,
+    dim: usize,
+}
+
+impl MultivariateNormal {
+    /// Constructs the distribution from a mean vector and a row major covariance matrix
+    ///
+    /// # Errors
+    /// Returns an error if the slices do not match `dim` or if the covariance matrix is not positive-definite
+    pub fn new(mean: &[f64], cov: &[f64], dim: usize) -> Result<Self, MultivariateError> {
+        if mean.len() != dim {
+            return Err(MultivariateError::DimensionMismatch);
+        }
+
+        Ok(MultivariateNormal {
+            mean: mean.to_vec(),
+            factor: cholesky(cov, dim)?,
+            dim,
+        })
+    }
+
+    /// The dimension of the distribution
+    pub const fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// The lower triangular Cholesky factor of the covariance matrix in row major order
+    pub fn cholesky_factor(&self) -> &[f64] {
+        &self.factor
+    }
+
+    /// Samples the distribution into `out`
+    ///
+    /// # Panics
+    /// Panics if `out` does not have length ``dim``
+    pub fn sample<T: Algorithm>(&self, prng: &mut PRNG<T>, out: &mut [f64]) {
+        assert_eq!(
+            out.len(),
+            self.dim,
+            "output buffer does not match the dimension"
+        );
+
+        for z in out.iter_mut() {
+            *z = prng.normal();
+        }
+
+        // x_i only depends on z_j for j <= i, so going from the last row up lets z be overwritten in place
+        for i in (0..self.dim).rev() {
+            let row = &self.factor[i * self.dim..=i * self.dim + i];
+            let lz: f64 = row.iter().zip(out.iter()).map(|(l, z)| l * z).sum();
+            out[i] = self.mean[i] + lz;
+        }
+    }
+}
+
+/// Dirichlet distribution Dir(alpha) over the probability simplex
+///
+/// Samples are drawn by normalizing independent gamma variates. When every alpha is small the gamma variates
+/// underflow to zero, so in that case the sample is built by beta stick-breaking with the beta variates
+/// computed in log space.
+///
+/// ```rust
+/// use smolprng::{Dirichlet, JsfLarge, PRNG};
+/// let mut prng = PRNG{generator: JsfLarge::default()};
+///
+/// let dirichlet = Dirichlet::new(&[0.5, 1.0, 2.0]).unwrap();
+/// let mut x = [0f64; 3];
+/// dirichlet.sample(&mut prng, &mut x);
+/// ```
+#[cfg(feature = "std")]
+pub struct Dirichlet {
+    alphas: Vec<f64>,
+    alpha_rests: Vec<f64>,
+}
+
+#[cfg(feature = "std")]
+impl Dirichlet {
+    /// The largest alpha for which the stick-breaking construction is used
+    const SMALL_ALPHA: f64 = 0.1;
+
+    /// Constructs the distribution from the concentration parameters
+    ///
+    /// # Errors
+    /// Returns an error if there are fewer than two alphas or if any alpha is not finite and positive
+    pub fn new(alphas: &[f64]) -> Result<Self, MultivariateError> {
+        if alphas.len() < 2 {
+            return Err(MultivariateError::DimensionMismatch);
+        }
+
+        if alphas.iter().any(|a| !a.is_finite() || *a <= 0f64) {
+            return Err(MultivariateError::InvalidParameter);
+        }
+
+        // sum_{j>i} alpha_j summed from the end, subtracting from the total can round the small tails to zero or below
+        let mut alpha_rests = alloc::vec![0f64; alphas.len()];
+        for i in (0..alphas.len() - 1).rev() {
+            alpha_rests[i] = alpha_rests[i + 1] + alphas[i + 1];
+        }
+
+        Ok(Dirichlet {
+            alphas: alphas.to_vec(),
+            alpha_rests,
+        })
+    }
+
+    /// The dimension of the distribution
+    pub const fn dim(&self) -> usize {
+        self.alphas.len()
+    }
+
+    /// Samples a point on the simplex into `out`
+    ///
+    /// # Panics
+    /// Panics if `out` does not have the same length as the alphas
+    pub fn sample<T: Algorithm>(&self, prng: &mut PRNG<T>, out: &mut [f64]) {
+        assert_eq!(
+            out.len(),
+            self.alphas.len(),
+            "output buffer does not match the dimension"
+        );
+
+        if self.alphas.iter().all(|a| *a < Self::SMALL_ALPHA) {
+            self.stick_breaking(prng, out);
+            return;
+        }
+
+        let mut sum = 0f64;
+        for (x, alpha) in out.iter_mut().zip(&self.alphas) {
+            *x = prng.gamma(*alpha, 1f64);
+            sum += *x;
+        }
+
+        for x in out.iter_mut() {
+            *x /= sum;
+        }
+    }
+
+    /// Stick-breaking construction, ``x_i = v_i prod_{j<i} (1 - v_j)`` with ``v_i ~ beta(alpha_i, sum_{j>i} alpha_j)``
+    fn stick_breaking<T: Algorithm>(&self, prng: &mut PRNG<T>, out: &mut [f64]) {
+        let k = self.alphas.len();
+        let mut stick = 1f64;
+
+        for ((x, alpha), alpha_rest) in out[..k - 1]
+            .iter_mut()
+            .zip(&self.alphas)
+            .zip(&self.alpha_rests)
+        {
+            let log_a = log_gamma_variate(prng, *alpha);
+            let log_b = log_gamma_variate(prng, *alpha_rest);
+
+            // v = A / (A + B) and 1 - v = B / (A + B) without leaving log space
+            *x = stick / (1f64 + (log_b - log_a).exp());
+            stick /= 1f64 + (log_a - log_b).exp();
+        }
+
+        out[k - 1] = stick;
+    }
+}
+
+/// Samples the logarithm of a Γ(α,1) variate, this is finite even when the variate itself underflows
+///
+/// Uses Γ(α) = Γ(α + 1) U^(1/α) for α < 1
+#[cfg(feature = "std")]
+fn log_gamma_variate<T: Algorithm>(prng: &mut PRNG<T>, alpha: f64) -> f64 {
+    if alpha < 1f64 {
+        prng.gamma(alpha + 1f64, 1f64).ln() + prng.gen_f64().ln() / alpha
+    } else {
+        prng.gamma(alpha, 1f64).ln()
+    }
+}
+
+/// Multinomial distribution, the counts of ``n`` independent draws from a categorical distribution
+///
+/// ```rust
+/// use smolprng::{JsfLarge, Multinomial, PRNG};
+/// let mut prng = PRNG{generator: JsfLarge::default()};
+///
+/// let mut counts = [0u64; 3];
+/// Multinomial::sample(&mut prng, 100, &[0.2, 0.3, 0.5], &mut counts);
+/// assert_eq!(counts.iter().sum::<u64>(), 100);
+/// ```
+pub struct Multinomial;
+
+impl Multinomial {
+    /// Samples the category counts of `n` draws into `out` by conditional binomials
+    ///
+    /// The probabilities do not need to be normalized, they are divided by their sum.
+    ///
+    /// # Panics
+    /// Panics if `out` and `probs` differ in length, or if the probabilities are negative, not finite, or all zero
+    pub fn sample<T: Algorithm>(prng: &mut PRNG<T>, n: u64, probs: &[f64], out: &mut [u64]) {
+        assert_eq!(
+            out.len(),
+            probs.len(),
+            "output buffer does not match the number of categories"
+        );
+        assert!(
+            probs.iter().all(|p| p.is_finite() && *p >= 0f64),
+            "probabilities must be finite and non-negative"
+        );
+
+        // sum_{j>=i} p_j summed from the end, subtracting from the total can round the small tails to zero or below
+        let last = probs.len() - 1;
+        let mut masses = alloc::vec![probs[last]; probs.len()];
+        for i in (0..last).rev() {
+            masses[i] = masses[i + 1] + probs[i];
+        }
+        assert!(masses[0] > 0f64, "probabilities must not all be zero");
+
+        let mut remaining = n;
+
+        for ((count, p), mass) in out[..last].iter_mut().zip(probs).zip(&masses) {
+            *count = if remaining == 0 || *mass == 0f64 {
+                0
+            } else {
+                prng.binomial(remaining, p / mass)
+            };
+            remaining -= *count;
+        }
+
+        out[last] = remaining;
+    }
+}
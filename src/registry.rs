@@ -0,0 +1,263 @@
+//! This module contains a runtime registry of the algorithms, so that a generator can be selected by name
+//!
+//! Every algorithm can be used through the object safe `DynAlgorithm` trait, and a boxed `DynAlgorithm` is itself
+//! an `Algorithm`, so a `PRNG` can be built over a generator that is only known at runtime.
+//!
+//! ```rust
+//! use smolprng::{registry, PRNG};
+//!
+//! let generator = registry::from_name("xoshiro256++", Some(42)).unwrap();
+//! let mut prng = PRNG{generator};
+//! let x = prng.gen_f64();
+//!
+//! for info in registry::list_algorithms() {
+//!     println!("{} {} bits of state, period {}", info.name, info.state_bits, info.period);
+//! }
+//! ```
+
+// every algorithm is registered
+#[allow(clippy::wildcard_imports)]
+use crate::algorithms::*;
+use crate::smol_core::{Algorithm, AlgorithmOutput};
+use alloc::boxed::Box;
+
+/// An object safe view of an `Algorithm`, implemented for every `Algorithm`
+pub trait DynAlgorithm {
+    /// The width of the native output of the algorithm in bits
+    fn output_bits(&self) -> u32;
+
+    /// The next native output of the algorithm, zero extended
+    fn next_output(&mut self) -> u128;
+
+    /// Generates a ``u32``, from the high bits of wider outputs or by concatenating narrower outputs
+    fn next_u32(&mut self) -> u32;
+
+    /// Generates a ``u64``, from the high bits of wider outputs or by concatenating narrower outputs
+    fn next_u64(&mut self) -> u64;
+
+    /// Fills `dest` with native outputs in little endian byte order, the last output is truncated if needed
+    fn fill_bytes(&mut self, dest: &mut [u8]);
+}
+
+impl<T: Algorithm> DynAlgorithm for T {
+    #[allow(clippy::cast_possible_truncation)]
+    fn output_bits(&self) -> u32 {
+        (8 * T::Output::SIZE) as u32
+    }
+
+    #[inline(always)]
+    fn next_output(&mut self) -> u128 {
+        self.gen().cast_to_u128()
+    }
+
+    #[inline(always)]
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_u32(&mut self) -> u32 {
+        let bits = 8 * T::Output::SIZE;
+        if bits >= 32 {
+            (self.next_output() >> (bits - 32)) as u32
+        } else {
+            (0..32 / bits).fold(0, |word, _| (word << bits) | self.next_output() as u32)
+        }
+    }
+
+    #[inline(always)]
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_u64(&mut self) -> u64 {
+        let bits = 8 * T::Output::SIZE;
+        if bits >= 64 {
+            (self.next_output() >> (bits - 64)) as u64
+        } else {
+            (0..64 / bits).fold(0, |word, _| (word << bits) | self.next_output() as u64)
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let mut chunks = dest.chunks_exact_mut(T::Output::SIZE);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_output().to_le_bytes()[..T::Output::SIZE]);
+        }
+
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+            let len = rest.len();
+            rest.copy_from_slice(&self.next_output().to_le_bytes()[..len]);
+        }
+    }
+}
+
+/// A boxed generator is an `Algorithm` with 64 bit output, narrower algorithms contribute several outputs per call
+///
+/// As an `Algorithm` the box is also a `DynAlgorithm` in its own right, so the `DynAlgorithm` methods called on the
+/// box see a 64 bit algorithm. Call them through ``as_mut()`` to reach the native outputs of the inner algorithm.
+impl Algorithm for Box<dyn DynAlgorithm> {
+    type Output = u64;
+
+    #[inline(always)]
+    fn gen(&mut self) -> Self::Output {
+        (**self).next_u64()
+    }
+}
+
+/// The description of an algorithm in the registry
+pub struct AlgorithmInfo {
+    /// The name used to select the algorithm
+    pub name: &'static str,
+    /// The name of the type that implements the algorithm
+    pub type_name: &'static str,
+    /// The size of the state in bits, counting keys and counters but not buffered outputs, indices or padding
+    pub state_bits: usize,
+    /// The width of the output in bits
+    pub output_bits: usize,
+    /// The period of the algorithm
+    pub period: &'static str,
+    /// Notes on the quality and intended use of the algorithm
+    pub notes: &'static str,
+    constructor: fn(Option<u64>) -> Box<dyn DynAlgorithm>,
+}
+
+impl AlgorithmInfo {
+    /// Constructs the algorithm, seeded from `seed` or in its default state
+    pub fn construct(&self, seed: Option<u64>) -> Box<dyn DynAlgorithm> {
+        (self.constructor)(seed)
+    }
+}
+
+fn construct<T: Algorithm + Default + From<u64> + 'static>(
+    seed: Option<u64>,
+) -> Box<dyn DynAlgorithm> {
+    Box::new(seed.map_or_else(T::default, T::from))
+}
+
+macro_rules! registry {
+    ($($name:literal => $algo_type:ident, $state_bits:literal, $period:literal, $notes:literal;)+) => {
+        const ALGORITHMS: &[AlgorithmInfo] = &[$(
+            AlgorithmInfo {
+                name: $name,
+                type_name: stringify!($algo_type),
+                state_bits: $state_bits,
+                output_bits: 8 * core::mem::size_of::<<$algo_type as Algorithm>::Output>(),
+                period: $period,
+                notes: $notes,
+                constructor: construct::<$algo_type>,
+            },
+        )+];
+    };
+}
+
+registry! {
+    "chacha8" => ChaCha8, 384, "2^68 words per stream, 2^64 streams per key",
+        "Bernstein's ChaCha stream cipher with 8 rounds, cryptographically secure with a secret key";
+    "chacha12" => ChaCha12, 384, "2^68 words per stream, 2^64 streams per key",
+        "Bernstein's ChaCha stream cipher with 12 rounds, cryptographically secure with a secret key";
+    "chacha20" => ChaCha20, 384, "2^68 words per stream, 2^64 streams per key",
+        "the RFC 7539 ChaCha20 stream cipher, cryptographically secure with a secret key";
+    "cmwc4096" => Cmwc4096, 131104, "about 2^131086",
+        "Marsaglia's complementary multiply with carry, an enormous period from 16 KiB of state";
+    "jsf32" => JsfSmall, 128, "about 2^126 on average",
+        "Bob Jenkins' small fast generator, the 32 bit version with rotations 27 and 17";
+    "jsf64" => JsfLarge, 256, "about 2^255 on average",
+        "Bob Jenkins' small fast generator, a good general purpose choice";
+    "kiss99" => Kiss99, 128, "about 2^123",
+        "Marsaglia's keep it simple combination of multiply with carry, xorshift and congruential generators";
+    "lcg" => LCG, 64, "2^64",
+        "Knuth's MMIX constants, the low bits are weak and it fails modern statistical tests";
+    "lehmer64" => Lehmer64, 128, "2^126",
+        "Lemire's 128 bit multiplicative congruential generator, outputs the high 64 bits";
+    "lfsr113" => Lfsr113, 128, "about 2^113",
+        "L'Ecuyer's combined Tausworthe generator, for reproducing other software, it fails linearity tests";
+    "mt19937" => Mt19937, 19968, "2^19937 - 1",
+        "Matsumoto and Nishimura's Mersenne twister, for reproducing other software, it fails linearity tests";
+    "mt19937-64" => Mt19937_64, 19968, "2^19937 - 1",
+        "the 64 bit Mersenne twister, for reproducing other software, it fails linearity tests";
+    "mrg32k3a" => Mrg32k3a, 192, "about 2^191",
+        "L'Ecuyer's combined multiple recursive generator, with the stream and substream jumps of RngStreams";
+    "msws" => MiddleSquare, 192, "at least 2^64",
+        "Widynski's middle square Weyl sequence, 192 of the 256 bits of state are used";
+    "mwc128" => Mwc128, 128, "about 2^127",
+        "Vigna's multiply with carry, very fast with a 128 bit multiply, jumps by modular exponentiation";
+    "mwc192" => Mwc192, 192, "about 2^191",
+        "Vigna's lag 2 multiply with carry, very fast with a 128 bit multiply, jumps by modular exponentiation";
+    "mwc256" => Mwc256, 256, "about 2^255",
+        "Vigna's lag 3 multiply with carry, very fast with a 128 bit multiply, jumps by modular exponentiation";
+    "philox4x32-10" => Philox4x32_10, 192, "2^130 words per key",
+        "Random123's counter based Philox, any block can be computed directly from its counter";
+    "romu-duo-jr" => RomuDuoJr, 128, "depends on the seed",
+        "Overton's fastest rotate multiply generator, its capacity is 2^51 outputs per job";
+    "romu-quad" => RomuQuad, 256, "depends on the seed",
+        "Overton's most robust rotate multiply generator, its capacity is 2^90 outputs per job";
+    "romu-trio" => RomuTrio, 192, "depends on the seed",
+        "Overton's general purpose rotate multiply generator, its capacity is 2^75 outputs per job";
+    "sfc32" => Sfc32, 128, "at least 2^32, about 2^127 on average",
+        "Chris Doty-Humphrey's small fast counting generator";
+    "sfc32-small" => Sfc32Small, 32, "2^32",
+        "a hashed Weyl sequence, too small a state for long simulations";
+    "sfc64" => Sfc64, 256, "at least 2^64, about 2^255 on average",
+        "Chris Doty-Humphrey's small fast counting generator, offered by NumPy";
+    "splitmix32" => SplitMix32, 128, "2^64",
+        "the upper half of a 64 bit SplitMix hash of a Weyl sequence";
+    "splitmix64" => SplitMix64, 64, "2^64",
+        "Steele, Lea and Flood's SplitMix, used to seed every other algorithm";
+    "squares32" => Squares32, 128, "2^64 per key",
+        "Widynski's counter based squares, seeds map to valid keys so they work as stream numbers";
+    "squares64" => Squares64, 128, "2^64 per key",
+        "Widynski's counter based squares, seeds map to valid keys so they work as stream numbers";
+    "step8" => StepGenerator8, 8, "2^8", "a counter for benchmarking, not random";
+    "step16" => StepGenerator16, 16, "2^16", "a counter for benchmarking, not random";
+    "step32" => StepGenerator32, 32, "2^32", "a counter for benchmarking, not random";
+    "step64" => StepGenerator64, 64, "2^64", "a counter for benchmarking, not random";
+    "step128" => StepGenerator128, 128, "2^128", "a counter for benchmarking, not random";
+    "taus88" => Taus88, 96, "about 2^88",
+        "L'Ecuyer's combined Tausworthe generator, for reproducing other software, it fails linearity tests";
+    "threefry4x64-20" => Threefry4x64_20, 512, "2^258 words per key",
+        "Random123's counter based Threefry, any block can be computed directly from its counter";
+    "wyrand" => WyRand, 64, "2^64", "Wang Yi's hashed Weyl sequence from wyhash, among the fastest generators";
+    "xoroshiro1024*" => XoroShiro1024Star, 1024, "2^1024 - 1",
+        "the low bits fail linearity tests, only use it to generate floating point numbers";
+    "xoroshiro1024++" => XoroShiro1024PlusPlus, 1024, "2^1024 - 1",
+        "Blackman and Vigna's generator for massively parallel use, with 2^512 jumps";
+    "xoroshiro1024**" => XoroShiro1024SuperStar, 1024, "2^1024 - 1",
+        "Blackman and Vigna's generator for massively parallel use, with 2^512 jumps";
+    "xoroshiro128+" => XoroShiro128Plus, 128, "2^128 - 1",
+        "the low bits fail linearity tests, only use it to generate floating point numbers";
+    "xoroshiro128++" => XoroShiro128PlusPlus, 128, "2^128 - 1", "Blackman and Vigna's all purpose generator";
+    "xoroshiro128**" => XoroShiro128SuperStar, 128, "2^128 - 1", "Blackman and Vigna's all purpose generator";
+    "xorshift32" => XorShift32, 32, "2^32 - 1", "Marsaglia's xorshift, fails many statistical tests";
+    "xorshift64" => XorShift64, 64, "2^64 - 1", "Marsaglia's xorshift, fails linearity tests";
+    "xorshift128" => XorShift128, 128, "2^128 - 1", "Marsaglia's xorshift, fails linearity tests";
+    "xorshift128+" => XorShift128Plus, 128, "2^128 - 1",
+        "the original 23, 17, 26 shifts, the low bits fail linearity tests";
+    "xorwow" => Xorwow, 192, "2^192 - 2^32",
+        "Marsaglia's xorshift with a Weyl sequence, the default generator of cuRAND, it fails linearity tests";
+    "xoshiro128+" => XoShiro128Plus, 128, "2^128 - 1",
+        "the low bits fail linearity tests, only use it to generate 32 bit floating point numbers";
+    "xoshiro128++" => XoShiro128PlusPlus, 128, "2^128 - 1", "Blackman and Vigna's 32 bit all purpose generator";
+    "xoshiro128**" => XoShiro128SuperStar, 128, "2^128 - 1", "Blackman and Vigna's 32 bit all purpose generator";
+    "xoshiro256+" => XoShiro256Plus, 256, "2^256 - 1",
+        "the low bits fail linearity tests, only use it to generate floating point numbers";
+    "xoshiro256++" => XoShiro256PlusPlus, 256, "2^256 - 1", "Blackman and Vigna's all purpose generator";
+    "xoshiro256**" => XoShiro256SuperStar, 256, "2^256 - 1", "Blackman and Vigna's all purpose generator";
+    "xoshiro512++" => XoShiro512PlusPlus, 512, "2^512 - 1",
+        "Blackman and Vigna's generator for massively parallel use, with 2^256 jumps";
+    "xoshiro512**" => XoShiro512SuperStar, 512, "2^512 - 1",
+        "Blackman and Vigna's generator for massively parallel use, with 2^256 jumps";
+}
+
+/// Lists every algorithm in the registry
+pub const fn list_algorithms() -> &'static [AlgorithmInfo] {
+    ALGORITHMS
+}
+
+/// Finds an algorithm by its registry name or its type name, ignoring case
+pub fn find(name: &str) -> Option<&'static AlgorithmInfo> {
+    ALGORITHMS.iter().find(|info| {
+        info.name.eq_ignore_ascii_case(name) || info.type_name.eq_ignore_ascii_case(name)
+    })
+}
+
+/// Constructs an algorithm by name, seeded from `seed` or in its default state
+///
+/// Returns ``None`` if there is no algorithm with that name
+pub fn from_name(name: &str, seed: Option<u64>) -> Option<Box<dyn DynAlgorithm>> {
+    find(name).map(|info| info.construct(seed))
+}
@@ -0,0 +1,132 @@
+//! This module contains reservoir sampling from iterators of unknown length
+//!
+//! The iterator is only traversed once and only the ``k`` sampled items are kept in memory.
+
+use crate::math;
+use crate::smol_core::{Algorithm, PRNG};
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+impl<T: Algorithm> PRNG<T> {
+    /// Samples `k` items uniformly without replacement from an iterator of unknown length
+    ///
+    /// Returns every item if the iterator has at most `k` items. The order of the sample is not random.
+    ///
+    /// Algorithm from "Reservoir-Sampling Algorithms of Time Complexity O(n(1 + log(N/n)))" - Kim-Hung Li ACM Transactions on Mathematical Software 1994
+    /// DOI: <https://doi.org/10.1145/198429.198435/>
+    ///
+    /// This is Algorithm L, which skips a geometrically distributed number of items between replacements so only
+    /// O(k(1 + log(N/k))) random numbers are needed.
+    pub fn sample_iter_reservoir<I: IntoIterator>(&mut self, iter: I, k: usize) -> Vec<I::Item> {
+        let mut iter = iter.into_iter();
+        let mut reservoir: Vec<I::Item> = iter.by_ref().take(k).collect();
+
+        if reservoir.len() < k || k == 0 {
+            return reservoir;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let inv_k = 1f64 / k as f64;
+        let mut w = math::exp(math::ln(self.gen_f64_open()) * inv_k);
+
+        loop {
+            let skip = math::ln(self.gen_f64_open()) / math::ln_1p(-w);
+
+            // the skip is non-negative so the cast is its floor, and a skip past usize::MAX saturates, which exhausts
+            // any iterator
+            #[allow(clippy::cast_sign_loss)]
+            match iter.nth(skip as usize) {
+                Some(item) => {
+                    let slot = self.gen_u64_in_range(k as u64) as usize;
+                    reservoir[slot] = item;
+                    w *= math::exp(math::ln(self.gen_f64_open()) * inv_k);
+                }
+                None => return reservoir,
+            }
+        }
+    }
+
+    /// Samples `k` items without replacement from an iterator of ``(item, weight)`` pairs of unknown length,
+    /// where the item is chosen with probability proportional to the weight among the items not yet chosen
+    ///
+    /// Items with a weight that is not finite and positive are never sampled. The order of the sample is not random.
+    ///
+    /// Algorithm from "Weighted random sampling with a reservoir" - Pavlos S. Efraimidis, Paul G. Spirakis Information Processing Letters 2006
+    /// DOI: <https://doi.org/10.1016/j.ipl.2005.11.003/>
+    ///
+    /// This is A-ExpJ, which jumps over a exponentially distributed amount of weight between replacements. The keys
+    /// are kept in log space so that small weights do not underflow.
+    pub fn sample_iter_weighted_reservoir<E, I: IntoIterator<Item = (E, f64)>>(
+        &mut self,
+        iter: I,
+        k: usize,
+    ) -> Vec<E> {
+        let mut iter = iter.into_iter().filter(|(_, w)| w.is_finite() && *w > 0f64);
+        let mut reservoir = BinaryHeap::with_capacity(k);
+
+        for (item, weight) in iter.by_ref().take(k) {
+            reservoir.push(MinKeyed {
+                key: math::ln(self.gen_f64_open()) / weight,
+                item,
+            });
+        }
+
+        if reservoir.len() == k && k > 0 {
+            let mut threshold = reservoir.peek().map_or(0f64, |min| min.key);
+            let mut jump = math::ln(self.gen_f64_open()) / threshold;
+
+            for (item, weight) in iter {
+                jump -= weight;
+                if jump <= 0f64 {
+                    // the new key is conditioned to beat the smallest key in the reservoir
+                    let t = math::exp(weight * threshold);
+                    let r = t + self.gen_f64() * (1f64 - t);
+                    reservoir.pop();
+                    reservoir.push(MinKeyed {
+                        key: math::ln(r) / weight,
+                        item,
+                    });
+
+                    threshold = reservoir.peek().map_or(0f64, |min| min.key);
+                    jump = math::ln(self.gen_f64_open()) / threshold;
+                }
+            }
+        }
+
+        reservoir.into_iter().map(|keyed| keyed.item).collect()
+    }
+
+    /// Generates a random ``f64`` uniformly distributed on (0,1], so that its logarithm is finite
+    #[inline(always)]
+    pub(crate) fn gen_f64_open(&mut self) -> f64 {
+        1f64 - self.gen_f64()
+    }
+}
+
+/// An item with a sort key, ordered so that a ``BinaryHeap`` keeps the smallest key on top
+#[allow(clippy::redundant_pub_crate)]
+pub(crate) struct MinKeyed<E> {
+    pub(crate) key: f64,
+    pub(crate) item: E,
+}
+
+impl<E> PartialEq for MinKeyed<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<E> Eq for MinKeyed<E> {}
+
+impl<E> PartialOrd for MinKeyed<E> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E> Ord for MinKeyed<E> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key.total_cmp(&self.key)
+    }
+}
@@ -0,0 +1,92 @@
+//! This module contains samplers for Haar distributed random rotations and orthogonal matrices
+
+use crate::smol_core::{Algorithm, PRNG};
+use std::f64::consts::PI;
+
+impl<T: Algorithm> PRNG<T> {
+    /// Samples a uniformly distributed unit quaternion ``[w, x, y, z]``
+    /// Algorithm from "Uniform Random Rotations" - Ken Shoemake Graphics Gems III 1992
+    pub fn random_quaternion(&mut self) -> [f64; 4] {
+        let u1 = self.gen_f64();
+        let theta_1 = 2f64 * PI * self.gen_f64();
+        let theta_2 = 2f64 * PI * self.gen_f64();
+
+        let r1 = (1f64 - u1).sqrt();
+        let r2 = u1.sqrt();
+
+        [
+            r2 * theta_2.cos(),
+            r1 * theta_1.sin(),
+            r1 * theta_1.cos(),
+            r2 * theta_2.sin(),
+        ]
+    }
+
+    /// Samples a uniformly distributed 3D rotation matrix, in row major order
+    /// Converts a random unit quaternion to its rotation matrix
+    pub fn random_rotation_3d(&mut self) -> [[f64; 3]; 3] {
+        let [w, x, y, z] = self.random_quaternion();
+
+        [
+            [
+                1f64 - 2f64 * (y * y + z * z),
+                2f64 * (x * y - w * z),
+                2f64 * (x * z + w * y),
+            ],
+            [
+                2f64 * (x * y + w * z),
+                1f64 - 2f64 * (x * x + z * z),
+                2f64 * (y * z - w * x),
+            ],
+            [
+                2f64 * (x * z - w * y),
+                2f64 * (y * z + w * x),
+                1f64 - 2f64 * (x * x + y * y),
+            ],
+        ]
+    }
+
+    /// Samples a Haar distributed ``n`` by ``n`` orthogonal matrix into `out`, in row major order
+    ///
+    /// Algorithm from "How to generate random matrices from the classical compact groups" - Francesco Mezzadri Notices of the AMS 2007
+    ///
+    /// The QR decomposition of a matrix of normal samples is taken by Gram-Schmidt, which always produces an R with
+    /// a positive diagonal, so Q needs no further sign correction. Every column is orthogonalized twice to keep Q
+    /// orthogonal to working precision.
+    ///
+    /// # Panics
+    /// Panics if `out` does not have length ``n * n``
+    pub fn random_orthogonal(&mut self, n: usize, out: &mut [f64]) {
+        assert_eq!(out.len(), n * n, "output buffer must be n by n");
+
+        for j in 0..n {
+            loop {
+                for i in 0..n {
+                    out[i * n + j] = self.normal();
+                }
+
+                for _pass in 0..2 {
+                    for k in 0..j {
+                        let proj: f64 = (0..n).map(|i| out[i * n + k] * out[i * n + j]).sum();
+                        for i in 0..n {
+                            out[i * n + j] -= proj * out[i * n + k];
+                        }
+                    }
+                }
+
+                let norm = (0..n)
+                    .map(|i| out[i * n + j] * out[i * n + j])
+                    .sum::<f64>()
+                    .sqrt();
+
+                // a column in the span of the previous ones happens with probability zero, but is resampled
+                if norm > 0f64 {
+                    for i in 0..n {
+                        out[i * n + j] /= norm;
+                    }
+                    break;
+                }
+            }
+        }
+    }
+}
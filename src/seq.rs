@@ -0,0 +1,210 @@
+//! This module contains the methods for randomly sampling and reordering slices
+//!
+//! Every method is built on the unbiased ``gen_u64_in_range``, so the results are exactly uniform. The slice methods
+//! do not allocate, the index generators return a ``Vec``.
+
+use crate::smol_core::{Algorithm, PRNG};
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+impl<T: Algorithm> PRNG<T> {
+    /// Generates a random index in [0,len) without bias
+    #[inline(always)]
+    fn gen_index(&mut self, len: usize) -> usize {
+        self.gen_u64_in_range(len as u64) as usize
+    }
+
+    /// Shuffles a slice in place, every permutation is equally likely
+    /// Algorithm from "The Art of Computer Programming Vol. 2" - Donald Knuth, Algorithm P (Fisher-Yates)
+    pub fn shuffle<E>(&mut self, slice: &mut [E]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.gen_index(i + 1);
+            slice.swap(i, j);
+        }
+    }
+
+    /// Partially shuffles a slice so that the first `k` elements are a uniform random ordered sample of the slice
+    ///
+    /// Only does `k` steps of Fisher-Yates, returns the shuffled prefix and the remaining elements.
+    /// If `k` is larger than the slice then the whole slice is shuffled.
+    pub fn partial_shuffle<'a, E>(
+        &mut self,
+        slice: &'a mut [E],
+        k: usize,
+    ) -> (&'a mut [E], &'a mut [E]) {
+        let n = slice.len();
+        let k = k.min(n);
+        for i in 0..k {
+            let j = i + self.gen_index(n - i);
+            slice.swap(i, j);
+        }
+        slice.split_at_mut(k)
+    }
+
+    /// Chooses a uniformly random element of a slice, returns ``None`` if the slice is empty
+    pub fn choose<'a, E>(&mut self, slice: &'a [E]) -> Option<&'a E> {
+        if slice.is_empty() {
+            return None;
+        }
+        Some(&slice[self.gen_index(slice.len())])
+    }
+
+    /// Chooses a uniformly random element of a slice mutably, returns ``None`` if the slice is empty
+    pub fn choose_mut<'a, E>(&mut self, slice: &'a mut [E]) -> Option<&'a mut E> {
+        if slice.is_empty() {
+            return None;
+        }
+        let i = self.gen_index(slice.len());
+        Some(&mut slice[i])
+    }
+
+    /// Chooses `k` distinct elements of a slice, every subset of size `k` is equally likely
+    ///
+    /// The elements are returned lazily in the order they appear in the slice. If `k` is larger than the slice
+    /// then every element is returned.
+    ///
+    /// Algorithm from "The Art of Computer Programming Vol. 2" - Donald Knuth, Algorithm S (selection sampling)
+    ///
+    /// ```rust
+    /// use smolprng::{JsfLarge, PRNG};
+    /// let mut prng = PRNG{generator: JsfLarge::default()};
+    ///
+    /// let values = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let mut chosen = [0; 3];
+    /// for (c, v) in chosen.iter_mut().zip(prng.choose_multiple(&values, 3)) {
+    ///     *c = *v;
+    /// }
+    /// assert!(chosen[0] < chosen[1] && chosen[1] < chosen[2]);
+    /// ```
+    pub fn choose_multiple<'a, E>(
+        &'a mut self,
+        slice: &'a [E],
+        k: usize,
+    ) -> ChooseMultiple<'a, T, E> {
+        ChooseMultiple {
+            prng: self,
+            slice,
+            position: 0,
+            needed: k.min(slice.len()),
+        }
+    }
+
+    /// Generates a uniformly random permutation of ``0..n``
+    pub fn random_permutation(&mut self, n: usize) -> Vec<usize> {
+        let mut permutation: Vec<usize> = (0..n).collect();
+        self.shuffle(&mut permutation);
+        permutation
+    }
+
+    /// Generates a uniformly random `k` element subset of ``0..n`` in increasing order
+    ///
+    /// Algorithm from "Programming pearls: a sample of brilliance" - Jon Bentley, Bob Floyd Communications of the ACM 1987
+    ///
+    /// Floyd's algorithm draws exactly `k` random numbers and only uses O(k) memory.
+    ///
+    /// # Panics
+    /// Panics if `k` is larger than `n`
+    pub fn random_combination(&mut self, n: usize, k: usize) -> Vec<usize> {
+        assert!(k <= n, "cannot choose more than n elements");
+        let mut chosen = BTreeSet::new();
+        for j in n - k..n {
+            let t = self.gen_index(j + 1);
+            if !chosen.insert(t) {
+                chosen.insert(j);
+            }
+        }
+        chosen.into_iter().collect()
+    }
+
+    /// Generates a uniformly random derangement of ``0..n``, a permutation where no element is in its own position
+    ///
+    /// Fisher-Yates is restarted as soon as a fixed point is placed, which is rejection sampling of permutations
+    /// with an expected e restarts.
+    ///
+    /// # Panics
+    /// Panics if `n` is 1, as there is no derangement of a single element
+    pub fn random_derangement(&mut self, n: usize) -> Vec<usize> {
+        assert_ne!(n, 1, "there is no derangement of one element");
+        let mut permutation: Vec<usize> = (0..n).collect();
+
+        'restart: loop {
+            for (i, p) in permutation.iter_mut().enumerate() {
+                *p = i;
+            }
+            for i in (1..n).rev() {
+                let j = self.gen_index(i + 1);
+                permutation.swap(i, j);
+                if permutation[i] == i {
+                    continue 'restart;
+                }
+            }
+            if n == 0 || permutation[0] != 0 {
+                return permutation;
+            }
+        }
+    }
+
+    /// Samples `k` distinct indices from ``0..n`` uniformly, in an unspecified order
+    ///
+    /// The method is picked by the density ``k/n``, dense samples use a partial Fisher-Yates shuffle of every index,
+    /// sparse samples use rejection of repeated indices, and the rest use Floyd's algorithm.
+    ///
+    /// # Panics
+    /// Panics if `k` is larger than `n`
+    pub fn index_sample(&mut self, n: usize, k: usize) -> Vec<usize> {
+        assert!(k <= n, "cannot choose more than n elements");
+
+        if k.saturating_mul(4) >= n {
+            let mut indices: Vec<usize> = (0..n).collect();
+            self.partial_shuffle(&mut indices, k);
+            indices.truncate(k);
+            indices
+        } else if k.saturating_mul(64) <= n {
+            // with k << n repeats are rare, so this takes about k draws
+            let mut seen = BTreeSet::new();
+            let mut indices = Vec::with_capacity(k);
+            while indices.len() < k {
+                let i = self.gen_index(n);
+                if seen.insert(i) {
+                    indices.push(i);
+                }
+            }
+            indices
+        } else {
+            self.random_combination(n, k)
+        }
+    }
+}
+
+/// Iterator over a uniformly random subset of a slice, created by ``PRNG::choose_multiple``
+pub struct ChooseMultiple<'a, T: Algorithm, E> {
+    prng: &'a mut PRNG<T>,
+    slice: &'a [E],
+    position: usize,
+    needed: usize,
+}
+
+impl<'a, T: Algorithm, E> Iterator for ChooseMultiple<'a, T, E> {
+    type Item = &'a E;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.needed > 0 {
+            let remaining = self.slice.len() - self.position;
+            let candidate = &self.slice[self.position];
+            self.position += 1;
+
+            // select with probability needed / remaining
+            if self.prng.gen_index(remaining) < self.needed {
+                self.needed -= 1;
+                return Some(candidate);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.needed, Some(self.needed))
+    }
+}
+
+impl<T: Algorithm, E> ExactSizeIterator for ChooseMultiple<'_, T, E> {}
@@ -1,14 +1,19 @@
 //! This is the central module in the `SmolPRNG` crate, as this is where the definitions of `PRNG`, `Algorithm`, and `AlgorithmOutput` reside
-#[cfg(not(feature = "std"))]
-use core::f64::consts::E;
+use crate::math;
 #[cfg(not(feature = "std"))]
 use core::ops::{BitAnd, BitOrAssign, Shl, Shr};
 
 #[cfg(feature = "std")]
-use std::f64::consts::{E, PI};
+use std::f64::consts::PI;
 #[cfg(feature = "std")]
 use std::ops::{BitAnd, BitOrAssign, Shl, Shr};
 
+/// The mean of the poisson variates that larger poisson variates are built from
+const POISSON_CHUNK: f64 = 10f64;
+
+/// ``e^10``, the normalizing constant of the poisson(10) CDF
+const EXP_POISSON_CHUNK: f64 = 22_026.465_794_806_718;
+
 /// PRNG is the central pseudo-random number generating front end.
 /// This is the front end of the entire package.
 pub struct PRNG<T: Algorithm> {
@@ -21,6 +26,11 @@ pub trait Algorithm {
     /// The output type of the Algorithm such as ``u32``, ect.
     type Output: AlgorithmOutput;
 
+    /// The revision of the output sequence of the Algorithm, this is incremented whenever a change alters the output
+    /// generated from a given state or the state that ``Default`` and ``From`` construct from a given seed, so that
+    /// results that depend on an exact stream can be checked against it
+    const REVISION: u32 = 1;
+
     /// Central generation function of the Algorithm trait, this takes in a state struct and returns the output of the algorithm
     fn gen(&mut self) -> Self::Output;
 }
@@ -168,6 +178,24 @@ impl<T: Algorithm> PRNG<T> {
         }
     }
 
+    ///Generates a random ``u64`` in the range of [0,range) without bias
+    /// Algorithm from "Fast Random Integer Generation in an Interval" - Daniel Lemire ACM Transactions on Modeling and Computer Simulation 2019
+    /// DOI: <https://doi.org/10.1145/3230636/>
+    ///
+    /// # Panics
+    /// Panics if `range` is zero
+    pub fn gen_u64_in_range(&mut self, range: u64) -> u64 {
+        assert!(range >= 1u64);
+        let mut m = u128::from(self.gen_u64()) * u128::from(range);
+        if (m as u64) < range {
+            let threshold = range.wrapping_neg() % range;
+            while (m as u64) < threshold {
+                m = u128::from(self.gen_u64()) * u128::from(range);
+            }
+        }
+        (m >> 64) as u64
+    }
+
     ///Generates a random ``f64`` uniformly distributed on [0,1)
     #[inline(always)]
     pub fn gen_f64(&mut self) -> f64 {
@@ -184,11 +212,10 @@ impl<T: Algorithm> PRNG<T> {
 
     /// Samples a normal distribution N(0,1) for one sample
     /// Algorithm from "A Note on the Generation of Random Normal Deviates" - G. E. P. Box, Mervin E. Muller The Annals of Mathematical Statistics 1958
-    #[cfg(feature = "std")]
     pub fn normal(&mut self) -> f64 {
         let (u, v) = self.disc2d();
         let s = u * u + v * v;
-        u * (-2f64 * (s.ln()) / s).sqrt()
+        u * math::sqrt(-2f64 * math::ln(s) / s)
     }
 
     ///Samples a bernoulli distribution with B(p)
@@ -309,32 +336,39 @@ impl<T: Algorithm> PRNG<T> {
     }
 
     /// Samples a poisson distribution
+    /// A poisson variate is the sum of poisson(10) variates sampled by inversion, and the remainder of the mean is
+    /// sampled by thinning one more poisson(10) variate, keeping each event with probability ``remainder / 10``.
+    /// As only the constant ``e^10`` is needed this does not depend on ``std``
     pub fn poisson(&mut self, l: f64) -> u64 {
         let mut n = 0;
-        let mut m = 0;
-        let cutoff_f = 10f64;
         let mut l_ = l;
 
-        while l_ > cutoff_f {
-            m += self.poisson(cutoff_f);
-            l_ -= cutoff_f;
+        while l_ > POISSON_CHUNK {
+            n += self.poisson_chunk();
+            l_ -= POISSON_CHUNK;
         }
 
-        let cdf = self.gen_f64() * E;
-        let mut prod = 1f64;
-        let mut denom = 1f64;
+        let events = self.poisson_chunk();
+        n + self.binomial(events, l_ / POISSON_CHUNK)
+    }
+
+    /// Samples a poisson(10) distribution by inversion of the CDF
+    fn poisson_chunk(&mut self) -> u64 {
+        let cdf = self.gen_f64() * EXP_POISSON_CHUNK;
+        let mut n = 0;
+        let mut term = 1f64;
         let mut sum = 1f64;
 
-        while sum < cdf {
+        // the term check ends the loop should rounding leave the sum just short of the scaled uniform
+        while sum < cdf && term > 0f64 {
             n += 1;
-            prod *= l;
             // implied loss of precision is safe as n will not hit 52 bits in any
             // reasonable calculation
-            denom *= n as f64;
-            sum += prod / denom;
+            term *= POISSON_CHUNK / n as f64;
+            sum += term;
         }
 
-        n + m
+        n
     }
 
     /// Samples a negative binomial distribution
@@ -0,0 +1,530 @@
+//! This module contains a battery of empirical statistical tests that can be run on any `Algorithm`
+//!
+//! The tests are intended to catch gross defects in a new or modified algorithm before it is used, they are not
+//! a replacement for full suites such as `TestU01` or `PractRand`. Every test consumes the full width of the raw
+//! algorithm output, most significant bit first, and reports a p-value. For a good generator the p-values are
+//! uniformly distributed on [0,1], so a p-value that is very close to zero is evidence of a defect.
+//!
+//! The monobit, block frequency, runs, serial, matrix rank and linear complexity tests follow NIST SP 800-22, the gap and
+//! poker tests follow Knuth's "The Art of Computer Programming Vol. 2", and the birthday spacings test follows
+//! Marsaglia's Diehard.
+//!
+//! ```rust
+//! use smolprng::{testing, SplitMix64, PRNG};
+//! let mut prng = PRNG{generator: SplitMix64::default()};
+//!
+//! for result in testing::run_battery(&mut prng) {
+//!     assert!(result.passed(1e-4), "{} failed with p = {}", result.name, result.p_value);
+//! }
+//! ```
+
+// the sample counts are far below 2^52, so converting them to f64 is exact
+#![allow(clippy::cast_precision_loss)]
+
+use crate::gf2::minimal_polynomial;
+use crate::smol_core::{Algorithm, AlgorithmOutput, PRNG};
+use alloc::vec::Vec;
+
+/// The outcome of a single statistical test
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TestResult {
+    /// The name of the test
+    pub name: &'static str,
+    /// The probability of a result at least as extreme as the observed one under the hypothesis of randomness
+    pub p_value: f64,
+}
+
+impl TestResult {
+    /// Returns true if the p-value is at least the significance level `alpha`
+    pub fn passed(&self, alpha: f64) -> bool {
+        self.p_value >= alpha
+    }
+}
+
+/// Runs every test in the module with default sample sizes, about 10 MB of generator output in total
+pub fn run_battery<T: Algorithm>(prng: &mut PRNG<T>) -> Vec<TestResult> {
+    let mut results = alloc::vec![
+        monobit(prng, 1 << 20),
+        block_frequency(prng, 1000, 1024),
+        runs(prng, 1 << 20),
+        poker(prng, 1 << 18),
+        gap(prng, 1 << 16),
+        birthday_spacings(prng, 200),
+        matrix_rank(prng, 1 << 12),
+        linear_complexity(prng, 200, 500),
+    ];
+    results.extend(serial(prng, 1 << 20, 8));
+    results
+}
+
+/// Frequency (monobit) test, checks that the proportion of ones in `n_bits` bits is close to one half
+pub fn monobit<T: Algorithm>(prng: &mut PRNG<T>, n_bits: usize) -> TestResult {
+    let mut bits = BitStream::new(prng);
+    let ones = (0..n_bits).filter(|_| bits.next_bit()).count();
+
+    let s = 2f64 * ones as f64 - n_bits as f64;
+    let s_obs = s.abs() / (n_bits as f64).sqrt();
+
+    TestResult {
+        name: "monobit",
+        p_value: erfc(s_obs / core::f64::consts::SQRT_2),
+    }
+}
+
+/// Frequency within a block test, checks that the proportion of ones in each of `n_blocks` blocks of `block_len`
+/// bits is close to one half
+pub fn block_frequency<T: Algorithm>(
+    prng: &mut PRNG<T>,
+    n_blocks: usize,
+    block_len: usize,
+) -> TestResult {
+    let mut bits = BitStream::new(prng);
+    let mut chi_squared = 0f64;
+
+    for _ in 0..n_blocks {
+        let ones = (0..block_len).filter(|_| bits.next_bit()).count();
+        let pi = ones as f64 / block_len as f64 - 0.5;
+        chi_squared += pi * pi;
+    }
+    chi_squared *= 4f64 * block_len as f64;
+
+    TestResult {
+        name: "block frequency",
+        p_value: igamc(n_blocks as f64 / 2f64, chi_squared / 2f64),
+    }
+}
+
+/// Runs test, checks that the number of runs of identical bits in `n_bits` bits is as expected
+pub fn runs<T: Algorithm>(prng: &mut PRNG<T>, n_bits: usize) -> TestResult {
+    let mut bits = BitStream::new(prng);
+    let mut ones = 0usize;
+    let mut n_runs = 1usize;
+    let mut last = bits.next_bit();
+
+    if last {
+        ones += 1;
+    }
+
+    for _ in 1..n_bits {
+        let bit = bits.next_bit();
+        if bit {
+            ones += 1;
+        }
+        if bit != last {
+            n_runs += 1;
+        }
+        last = bit;
+    }
+
+    let n = n_bits as f64;
+    let pi = ones as f64 / n;
+
+    // the runs test is only applicable if the frequency test would pass
+    let p_value = if (pi - 0.5).abs() >= 2f64 / n.sqrt() {
+        0f64
+    } else {
+        let expected = 2f64 * n * pi * (1f64 - pi);
+        erfc((n_runs as f64 - expected).abs() / (2f64 * (2f64 * n).sqrt() * pi * (1f64 - pi)))
+    };
+
+    TestResult {
+        name: "runs",
+        p_value,
+    }
+}
+
+/// Poker test on 4 bit hands, checks that each of the 16 possible values of `n_hands` non-overlapping nibbles is
+/// equally likely
+pub fn poker<T: Algorithm>(prng: &mut PRNG<T>, n_hands: usize) -> TestResult {
+    let mut bits = BitStream::new(prng);
+    let mut counts = [0usize; 16];
+
+    for _ in 0..n_hands {
+        counts[bits.next_bits(4) as usize] += 1;
+    }
+
+    let expected = [n_hands as f64 / 16f64; 16];
+    TestResult {
+        name: "poker",
+        p_value: chi_squared_p_value(&counts, &expected),
+    }
+}
+
+/// Serial test, checks that each of the 2^`m` overlapping `m` bit patterns in `n_bits` bits is equally likely
+///
+/// The sequence is wrapped around so that every bit starts a pattern. Following NIST SP 800-22 the statistic is
+/// differenced against the counts of the `m - 1` and `m - 2` bit patterns, giving two p-values.
+///
+/// # Panics
+/// Panics if `m` is not between 2 and 20, or if `n_bits` is less than `m`
+pub fn serial<T: Algorithm>(prng: &mut PRNG<T>, n_bits: usize, m: u32) -> [TestResult; 2] {
+    assert!(
+        (2..=20).contains(&m),
+        "the pattern length must be between 2 and 20"
+    );
+    assert!(
+        n_bits >= m as usize,
+        "the sequence must be at least as long as a pattern"
+    );
+
+    let mut bits = BitStream::new(prng);
+    let mut counts = [m, m - 1, m - 2].map(|len| alloc::vec![0usize; 1 << len]);
+    let mut head = 0u64;
+    let mut window = 0u64;
+
+    // the pattern of length len ending at bit j of the wrapped sequence starts at j + 1 - len
+    for j in 0..n_bits + m as usize - 1 {
+        let bit = if j < n_bits {
+            bits.next_bit()
+        } else {
+            (head >> (m as usize - 2 - (j - n_bits))) & 1 == 1
+        };
+        if j < m as usize - 1 {
+            head = (head << 1) | u64::from(bit);
+        }
+        window = (window << 1) | u64::from(bit);
+
+        for (len, count) in [m, m - 1, m - 2].into_iter().zip(&mut counts) {
+            let len = len as usize;
+            if j + 1 >= len && j + 1 < n_bits + len {
+                count[(window & ((1 << len) - 1)) as usize] += 1;
+            }
+        }
+    }
+
+    // the empty pattern occurs n times, so the m = 2 case needs no special handling
+    let n = n_bits as f64;
+    let [psi_m, psi_m1, psi_m2] = counts.map(|count| {
+        let sum_squares: f64 = count.iter().map(|c| *c as f64 * *c as f64).sum();
+        count.len() as f64 / n * sum_squares - n
+    });
+
+    let patterns = f64::from(1u32 << m);
+    let first_difference = psi_m - psi_m1;
+    let second_difference = psi_m - 2f64 * psi_m1 + psi_m2;
+    [
+        TestResult {
+            name: "serial",
+            p_value: igamc(patterns / 4f64, first_difference / 2f64),
+        },
+        TestResult {
+            name: "serial second difference",
+            p_value: igamc(patterns / 8f64, second_difference / 2f64),
+        },
+    ]
+}
+
+/// Gap test, checks the distribution of the lengths of `n_gaps` gaps between uniform samples that land in [0, 1/2)
+pub fn gap<T: Algorithm>(prng: &mut PRNG<T>, n_gaps: usize) -> TestResult {
+    const MAX_GAP: usize = 10;
+    let mut bits = BitStream::new(prng);
+    let mut counts = [0usize; MAX_GAP + 1];
+
+    for _ in 0..n_gaps {
+        let mut length = 0;
+        while bits.next_bits(32) >= 1 << 31 {
+            length += 1;
+        }
+        counts[length.min(MAX_GAP)] += 1;
+    }
+
+    // P(gap = r) = p (1 - p)^r with p = 1/2 for r < MAX_GAP, and the tail is (1 - p)^MAX_GAP
+    let mut expected = [0f64; MAX_GAP + 1];
+    let mut tail = n_gaps as f64;
+    for e in &mut expected[..MAX_GAP] {
+        *e = tail * 0.5;
+        tail *= 0.5;
+    }
+    expected[MAX_GAP] = tail;
+
+    TestResult {
+        name: "gap",
+        p_value: chi_squared_p_value(&counts, &expected),
+    }
+}
+
+/// Birthday spacings test, checks the number of repeated spacings between sorted random birthdays
+///
+/// In each of `n_trials` trials 512 birthdays are drawn from a year of 2^24 days, the number of repeated spacings
+/// is then asymptotically Poisson with mean 2
+pub fn birthday_spacings<T: Algorithm>(prng: &mut PRNG<T>, n_trials: usize) -> TestResult {
+    const BIRTHDAYS: usize = 512;
+    const DAY_BITS: u32 = 24;
+    let mut bits = BitStream::new(prng);
+    let mut birthdays = [0u64; BIRTHDAYS];
+    let mut spacings = [0u64; BIRTHDAYS];
+    let mut repeats = 0usize;
+
+    for _ in 0..n_trials {
+        for b in &mut birthdays {
+            *b = bits.next_bits(DAY_BITS);
+        }
+        birthdays.sort_unstable();
+
+        spacings[0] = birthdays[0];
+        for i in 1..BIRTHDAYS {
+            spacings[i] = birthdays[i] - birthdays[i - 1];
+        }
+        spacings.sort_unstable();
+
+        repeats += spacings.windows(2).filter(|w| w[0] == w[1]).count();
+    }
+
+    // lambda = m^3 / (4n) per trial, the total is poisson with mean n_trials * lambda
+    let lambda = n_trials as f64 * (BIRTHDAYS as f64).powi(3) / 4f64 / f64::from(1u32 << DAY_BITS);
+    let x = repeats as f64;
+    let lower = igamc(x + 1f64, lambda);
+    let upper = if repeats == 0 { 1f64 } else { igam(x, lambda) };
+
+    TestResult {
+        name: "birthday spacings",
+        p_value: (2f64 * lower.min(upper)).min(1f64),
+    }
+}
+
+/// Binary matrix rank test, checks the distribution of the ranks over GF(2) of `n_matrices` random 32 by 32
+/// binary matrices
+pub fn matrix_rank<T: Algorithm>(prng: &mut PRNG<T>, n_matrices: usize) -> TestResult {
+    let mut bits = BitStream::new(prng);
+    let mut counts = [0usize; 3];
+
+    for _ in 0..n_matrices {
+        let mut rows = [0u32; 32];
+        for row in &mut rows {
+            *row = bits.next_bits(32) as u32;
+        }
+
+        match gf2_rank(&mut rows) {
+            32 => counts[0] += 1,
+            31 => counts[1] += 1,
+            _ => counts[2] += 1,
+        }
+    }
+
+    let probabilities = [0.2888, 0.5776, 0.1336];
+    let expected = probabilities.map(|p| p * n_matrices as f64);
+
+    TestResult {
+        name: "matrix rank",
+        p_value: chi_squared_p_value(&counts, &expected),
+    }
+}
+
+/// Linear complexity test, checks the distribution of the linear complexity of `n_blocks` blocks of `block_len`
+/// bits, computed by the Berlekamp-Massey algorithm
+pub fn linear_complexity<T: Algorithm>(
+    prng: &mut PRNG<T>,
+    n_blocks: usize,
+    block_len: usize,
+) -> TestResult {
+    let mut bits = BitStream::new(prng);
+    let mut block = alloc::vec![false; block_len];
+    let mut counts = [0usize; 7];
+
+    let m = block_len as f64;
+    // is_multiple_of would need Rust 1.87
+    #[allow(clippy::manual_is_multiple_of)]
+    let sign = if block_len % 2 == 0 { 1f64 } else { -1f64 };
+    let mean = m / 2f64 + (9f64 - sign) / 36f64 - (m / 3f64 + 2f64 / 9f64) / 2f64.powf(m);
+
+    for _ in 0..n_blocks {
+        for b in &mut block {
+            *b = bits.next_bit();
+        }
+
+        // the linear complexity, the length of the shortest LFSR that generates the block
+        let complexity = minimal_polynomial(&block).degree().unwrap_or(0);
+        let t = sign * (complexity as f64 - mean) + 2f64 / 9f64;
+        let category = match t {
+            t if t <= -2.5 => 0,
+            t if t <= -1.5 => 1,
+            t if t <= -0.5 => 2,
+            t if t <= 0.5 => 3,
+            t if t <= 1.5 => 4,
+            t if t <= 2.5 => 5,
+            _ => 6,
+        };
+        counts[category] += 1;
+    }
+
+    let probabilities = [0.010417, 0.03125, 0.125, 0.5, 0.25, 0.0625, 0.020833];
+    let expected = probabilities.map(|p| p * n_blocks as f64);
+
+    TestResult {
+        name: "linear complexity",
+        p_value: chi_squared_p_value(&counts, &expected),
+    }
+}
+
+/// Reads the raw algorithm output as a stream of bits, most significant bit first
+struct BitStream<'a, T: Algorithm> {
+    prng: &'a mut PRNG<T>,
+    word: u128,
+    remaining: u32,
+}
+
+impl<'a, T: Algorithm> BitStream<'a, T> {
+    const fn new(prng: &'a mut PRNG<T>) -> Self {
+        BitStream {
+            prng,
+            word: 0,
+            remaining: 0,
+        }
+    }
+
+    fn next_bit(&mut self) -> bool {
+        self.next_bits(1) == 1
+    }
+
+    /// Reads the next `k` bits, for `k` up to 64
+    fn next_bits(&mut self, k: u32) -> u64 {
+        let mut out = 0u64;
+        let mut needed = k;
+
+        while needed > 0 {
+            if self.remaining == 0 {
+                self.word = self.prng.generator.gen().cast_to_u128();
+                self.remaining = (T::Output::SIZE * 8) as u32;
+            }
+
+            let take = needed.min(self.remaining);
+            let chunk = (self.word >> (self.remaining - take)) & ((1u128 << take) - 1);
+            out = out.checked_shl(take).unwrap_or(0) | chunk as u64;
+
+            self.remaining -= take;
+            needed -= take;
+        }
+
+        out
+    }
+}
+
+/// Computes the rank over GF(2) of a 32 by 32 binary matrix by Gaussian elimination
+fn gf2_rank(rows: &mut [u32; 32]) -> usize {
+    let mut rank = 0;
+
+    for bit in 0..32 {
+        let mask = 1u32 << bit;
+        if let Some(pivot) = (rank..32).find(|&r| rows[r] & mask != 0) {
+            rows.swap(rank, pivot);
+            for r in 0..32 {
+                if r != rank && rows[r] & mask != 0 {
+                    rows[r] ^= rows[rank];
+                }
+            }
+            rank += 1;
+        }
+    }
+
+    rank
+}
+
+/// Pearson's chi squared test of observed counts against expected counts
+fn chi_squared_p_value(observed: &[usize], expected: &[f64]) -> f64 {
+    let chi_squared: f64 = observed
+        .iter()
+        .zip(expected)
+        .map(|(o, e)| (*o as f64 - e) * (*o as f64 - e) / e)
+        .sum();
+    let degrees_of_freedom = (observed.len() - 1) as f64;
+    igamc(degrees_of_freedom / 2f64, chi_squared / 2f64)
+}
+
+/// The complementary error function
+fn erfc(x: f64) -> f64 {
+    if x >= 0f64 {
+        igamc(0.5, x * x)
+    } else {
+        2f64 - igamc(0.5, x * x)
+    }
+}
+
+/// The regularized lower incomplete gamma function P(a,x)
+fn igam(a: f64, x: f64) -> f64 {
+    1f64 - igamc(a, x)
+}
+
+/// The regularized upper incomplete gamma function Q(a,x)
+/// Series and continued fraction expansions from "Numerical Recipes" - Press et al. Section 6.2
+#[allow(clippy::many_single_char_names)]
+fn igamc(a: f64, x: f64) -> f64 {
+    const EPS: f64 = 1e-15;
+    const TINY: f64 = 1e-300;
+
+    if x <= 0f64 {
+        return 1f64;
+    }
+
+    let log_prefactor = -x + a * x.ln() - ln_gamma(a);
+
+    if x < a + 1f64 {
+        let mut ap = a;
+        let mut term = 1f64 / a;
+        let mut sum = term;
+        for _ in 0..10_000 {
+            ap += 1f64;
+            term *= x / ap;
+            sum += term;
+            if term.abs() < sum.abs() * EPS {
+                break;
+            }
+        }
+        1f64 - sum * log_prefactor.exp()
+    } else {
+        let mut b = x + 1f64 - a;
+        let mut c = 1f64 / TINY;
+        let mut d = 1f64 / b;
+        let mut h = d;
+        for i in 1..10_000 {
+            let an = -f64::from(i) * (f64::from(i) - a);
+            b += 2f64;
+            d = an * d + b;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = b + an / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1f64 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1f64).abs() < EPS {
+                break;
+            }
+        }
+        h * log_prefactor.exp()
+    }
+}
+
+/// The natural logarithm of the gamma function for positive arguments by the Lanczos approximation (g = 7, n = 9)
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // reflection formula
+        let pi = core::f64::consts::PI;
+        return (pi / (pi * x).sin()).ln() - ln_gamma(1f64 - x);
+    }
+
+    let x = x - 1f64;
+    let t = x + 7.5;
+    let series = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |sum, (i, c)| {
+            sum + c / (x + i as f64 + 1f64)
+        });
+
+    0.5 * (2f64 * core::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
@@ -0,0 +1,224 @@
+//! This module contains structures for sampling indices with probability proportional to a set of weights
+
+#[cfg(feature = "std")]
+use crate::reservoir::MinKeyed;
+use crate::smol_core::{Algorithm, PRNG};
+#[cfg(feature = "std")]
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+
+/// A weighted index sampler that supports changing weights after construction.
+///
+/// The weights are stored in the leaves of a complete binary sum tree, so that `update`, `push` and `sample` are all
+/// O(log n). Every internal node is recomputed from its children on update, so floating point error does not
+/// accumulate as it would with a delta-updated Fenwick tree.
+///
+/// Can be used in the following ways.
+/// ```rust
+/// use smolprng::{DynamicWeightedIndex, JsfLarge, PRNG};
+/// let mut prng = PRNG{generator: JsfLarge::default()};
+///
+/// let mut rates = DynamicWeightedIndex::new(&[1.0, 2.0, 0.0]);
+/// rates.push(4.0);
+/// rates.update(0, 0.0);
+///
+/// let event = rates.sample(&mut prng).unwrap(); // 1 or 3
+/// assert!(event == 1 || event == 3);
+/// ```
+pub struct DynamicWeightedIndex {
+    tree: Vec<f64>,
+    capacity: usize,
+    len: usize,
+}
+
+impl DynamicWeightedIndex {
+    /// Builds the sampler from an initial set of weights in O(n)
+    ///
+    /// # Panics
+    /// Panics if any weight is negative or not finite
+    pub fn new(weights: &[f64]) -> Self {
+        let capacity = weights.len().next_power_of_two();
+        let mut tree = alloc::vec![0f64; 2 * capacity];
+
+        for (leaf, &w) in tree[capacity..].iter_mut().zip(weights) {
+            assert!(
+                w.is_finite() && w >= 0f64,
+                "weights must be finite and non-negative"
+            );
+            *leaf = w;
+        }
+
+        for i in (1..capacity).rev() {
+            tree[i] = tree[2 * i] + tree[2 * i + 1];
+        }
+
+        DynamicWeightedIndex {
+            tree,
+            capacity,
+            len: weights.len(),
+        }
+    }
+
+    /// The number of weights in the sampler
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if there are no weights in the sampler
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The sum of all of the weights
+    pub fn total(&self) -> f64 {
+        self.tree[1]
+    }
+
+    /// Returns the weight at index `i`
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds
+    pub fn weight(&self, i: usize) -> f64 {
+        assert!(i < self.len, "index out of bounds");
+        self.tree[self.capacity + i]
+    }
+
+    /// Sets the weight at index `i` in O(log n)
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds or if the weight is negative or not finite
+    pub fn update(&mut self, i: usize, weight: f64) {
+        assert!(i < self.len, "index out of bounds");
+        assert!(
+            weight.is_finite() && weight >= 0f64,
+            "weights must be finite and non-negative"
+        );
+
+        let mut node = self.capacity + i;
+        self.tree[node] = weight;
+
+        while node > 1 {
+            node /= 2;
+            self.tree[node] = self.tree[2 * node] + self.tree[2 * node + 1];
+        }
+    }
+
+    /// Appends a new weight in amortized O(log n)
+    ///
+    /// # Panics
+    /// Panics if the weight is negative or not finite
+    pub fn push(&mut self, weight: f64) {
+        if self.len == self.capacity {
+            self.grow();
+        }
+        self.len += 1;
+        self.update(self.len - 1, weight);
+    }
+
+    /// Samples an index with probability proportional to its weight in O(log n)
+    ///
+    /// Returns ``None`` if the total weight is zero
+    pub fn sample<T: Algorithm>(&self, prng: &mut PRNG<T>) -> Option<usize> {
+        if self.total() <= 0f64 {
+            return None;
+        }
+
+        let mut u = prng.gen_f64() * self.total();
+        let mut node = 1;
+
+        while node < self.capacity {
+            let left = self.tree[2 * node];
+            // the zero check on the right child guards against rounding walking into an empty subtree
+            if u < left || self.tree[2 * node + 1] <= 0f64 {
+                node *= 2;
+            } else {
+                u -= left;
+                node = 2 * node + 1;
+            }
+        }
+
+        Some(node - self.capacity)
+    }
+
+    /// Samples the next event of a continuous time Markov chain where the weights are the event rates.
+    ///
+    /// Returns the index of the event and the waiting time until it occurs, as in the Gillespie direct method.
+    /// Returns ``None`` if the total rate is zero
+    #[cfg(feature = "std")]
+    pub fn gillespie_step<T: Algorithm>(&self, prng: &mut PRNG<T>) -> Option<(usize, f64)> {
+        let event = self.sample(prng)?;
+        Some((event, prng.exponential(self.total())))
+    }
+
+    /// Doubles the number of leaves and rebuilds the internal nodes
+    fn grow(&mut self) {
+        let capacity = 2 * self.capacity;
+        let mut tree = alloc::vec![0f64; 2 * capacity];
+
+        tree[capacity..capacity + self.len]
+            .copy_from_slice(&self.tree[self.capacity..self.capacity + self.len]);
+
+        for i in (1..capacity).rev() {
+            tree[i] = tree[2 * i] + tree[2 * i + 1];
+        }
+
+        self.tree = tree;
+        self.capacity = capacity;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Algorithm> PRNG<T> {
+    /// Chooses `k` distinct indices of `weights` without replacement, where each successive index is chosen with
+    /// probability proportional to its weight among the indices not yet chosen
+    ///
+    /// The indices are returned in the order they would have been drawn. Weights that are not finite and positive
+    /// are never chosen, so fewer than `k` indices are returned if there are fewer than `k` positive weights.
+    ///
+    /// Algorithm from "Weighted random sampling with a reservoir" - Pavlos S. Efraimidis, Paul G. Spirakis Information Processing Letters 2006
+    /// DOI: <https://doi.org/10.1016/j.ipl.2005.11.003/>
+    ///
+    /// Each index gets an exponential key ``E_i / w_i`` and the `k` smallest keys are chosen. When `k` is a small
+    /// fraction of the weights the keys are filtered through a heap of size `k`, otherwise they are partitioned in place.
+    ///
+    /// ```rust
+    /// use smolprng::{JsfLarge, PRNG};
+    /// let mut prng = PRNG{generator: JsfLarge::default()};
+    ///
+    /// let chosen = prng.choose_weighted_multiple(&[1.0, 0.0, 5.0, 2.0], 2);
+    /// assert_eq!(chosen.len(), 2);
+    /// assert!(!chosen.contains(&1));
+    /// ```
+    pub fn choose_weighted_multiple(&mut self, weights: &[f64], k: usize) -> Vec<usize> {
+        // keys are negated so that the largest keys are the ones chosen
+        let keys = weights
+            .iter()
+            .enumerate()
+            .filter(|(_, w)| w.is_finite() && **w > 0f64)
+            .map(|(i, w)| MinKeyed {
+                key: -self.exponential(*w),
+                item: i,
+            });
+
+        let mut chosen: Vec<MinKeyed<usize>> = if k.saturating_mul(4) < weights.len() {
+            let mut heap = BinaryHeap::with_capacity(k + 1);
+            for keyed in keys {
+                heap.push(keyed);
+                if heap.len() > k {
+                    heap.pop();
+                }
+            }
+            heap.into_vec()
+        } else {
+            let mut all: Vec<MinKeyed<usize>> = keys.collect();
+            if k < all.len() {
+                all.select_nth_unstable(k);
+                all.truncate(k);
+            }
+            all
+        };
+
+        chosen.sort_unstable();
+        chosen.into_iter().map(|keyed| keyed.item).collect()
+    }
+}